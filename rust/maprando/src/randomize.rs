@@ -1,5 +1,7 @@
+mod door_link_cache;
 pub mod escape_timer;
 mod run_speed;
+mod transition_registry;
 
 use crate::{
     game_data::{
@@ -20,12 +22,22 @@ use hashbrown::{HashMap, HashSet};
 use log::info;
 use rand::SeedableRng;
 use rand::{seq::SliceRandom, Rng};
+use rayon::prelude::*;
 use run_speed::{
-    get_extra_run_speed_tiles, get_max_extra_run_speed, get_shortcharge_max_extra_run_speed,
-    get_shortcharge_min_extra_run_speed,
+    compute_run_frames, get_extra_run_speed_tiles, get_max_extra_run_speed,
+    get_shortcharge_max_extra_run_speed, get_shortcharge_min_extra_run_speed,
+    min_cost_reposition_frames, round_trip_run_frames, speed_and_frames_for_runway,
 };
 use serde_derive::{Deserialize, Serialize};
-use std::{cmp::min, convert::TryFrom, hash::Hash, iter, time::SystemTime};
+use std::{
+    cmp::{min, Reverse},
+    collections::{BinaryHeap, VecDeque},
+    convert::TryFrom,
+    hash::Hash,
+    iter,
+    path::Path,
+    time::SystemTime,
+};
 use strum::VariantNames;
 
 use crate::game_data::GameData;
@@ -48,12 +60,38 @@ pub enum ProgressionRate {
 pub enum ItemPlacementStyle {
     Neutral,
     Forced,
+    // Instead of committing to the first key-item selection that provides progression, generate
+    // `width` candidate selections for the step and keep whichever scores best (by how much
+    // reachability it opens up). Width 1 is equivalent to the sequential retry behavior used by
+    // `Neutral`/`Forced`. Only takes effect when `DifficultyConfig::beam_width` is 1; the coarser
+    // trajectory-level beam search that field drives already covers this (see
+    // `multi_attempt_select_items`), so the two aren't applied on top of each other.
+    Beam {
+        width: usize,
+    },
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
 pub enum ItemPriorityStrength {
     Moderate,
     Heavy,
+    // Build the precedence list from `DifficultyConfig::item_weights` instead of the fixed
+    // Early/Moderate/Late groups (see `weighted_item_precedence`).
+    Weighted,
+}
+
+/// A single entry of the weighted drop table used by `ItemPriorityStrength::Weighted`. `weight`
+/// is the item's base sampling weight; `early_mult`/`mid_mult`/`late_mult` scale that weight
+/// depending on what fraction of the precedence list has been built so far, so a preset can
+/// express e.g. "charge weighted heavily early, screw attack weighted late" as data instead of
+/// new special-case code. An item with no entry defaults to a flat weight of 1.0 in every phase.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ItemWeightEntry {
+    pub item: Item,
+    pub weight: f32,
+    pub early_mult: f32,
+    pub mid_mult: f32,
+    pub late_mult: f32,
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
@@ -163,6 +201,14 @@ pub enum StartLocationMode {
     Ship,
     Random,
     Escape,
+    // Like `Random`, but candidates are ranked by `score_start_location_candidate` and tried in
+    // descending-score order instead of uniformly at random, so well-connected starts are
+    // preferred; falls through the ranked list the same way `Random`'s loop falls through random
+    // draws if a top candidate doesn't pan out.
+    Greedy,
+    // Same ranking as `Greedy`, but ties are broken randomly so repeated generations with the same
+    // settings aren't always identical, while still avoiding a poorly connected start.
+    Scored,
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
@@ -215,6 +261,9 @@ pub enum MotherBrainFight {
 pub struct DebugOptions {
     pub new_game_extra: bool,
     pub extended_spoiler: bool,
+    // When enabled, cross-room `Link`s record a human-readable breakdown of their requirement
+    // tree (tech/item names, heat frames, etc.) in `strat_notes`, for the spoiler/logic viewer.
+    pub strat_explanations: bool,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
@@ -244,6 +293,21 @@ pub struct DifficultyConfig {
     pub semi_filler_items: Vec<Item>,
     pub filler_items: Vec<Item>,
     pub early_filler_items: Vec<Item>,
+    // Relative weight for each item type when ordering the "mix" portion of the filler pool (see
+    // `select_filler_items`). An item not listed here defaults to a weight of 1.0, so leaving this
+    // empty reproduces the previous uniform shuffle.
+    pub filler_item_weights: Vec<(Item, f32)>,
+    // Drop-table entries used by `ItemPriorityStrength::Weighted` (see `weighted_item_precedence`).
+    // An item with no entry here falls back to a flat weight of 1.0 in every phase.
+    pub item_weights: Vec<ItemWeightEntry>,
+    // Width of the beam-search item-placement frontier used by `randomize_beam` (see there for
+    // details). 1 reproduces the original single-state greedy placement in `randomize`; wider
+    // values keep that many candidate partial placements alive per step, so a dead-end branch
+    // falls back to a sibling instead of failing the whole attempt. Takes precedence over
+    // `ItemPlacementStyle::Beam`'s narrower per-step key-item beam (see `multi_attempt_select_items`)
+    // when both are set, since `randomize_beam` already branches and scores this step's whole
+    // resulting state, making the per-step beam redundant.
+    pub beam_width: usize,
     pub resource_multiplier: f32,
     pub gate_glitch_leniency: Capacity,
     pub door_stuck_leniency: Capacity,
@@ -274,8 +338,33 @@ pub struct DifficultyConfig {
     pub respin: bool,
     pub infinite_space_jump: bool,
     pub momentum_conservation: bool,
+    // When set, spoiler routes are computed with a Dijkstra search that minimizes resource
+    // expenditure instead of being reconstructed from whichever traversal trail first reached
+    // the vertex, at the cost of some extra spoiler-generation time.
+    pub optimize_spoiler_routes: bool,
     // Game variations:
     pub objectives: Vec<Objective>,
+    // Number of locked doors (in addition to whatever `doors_mode` places) that are gated by
+    // progress on `objectives` instead of by an item. 0 disables this entirely.
+    // CAVEAT: `crate::traverse` has no requirement that enforces this lock (see
+    // `DoorType::Objective`'s doc comment), so this is feature-gated off: `randomize_doors` never
+    // actually places a door of this type, and just logs a warning if this is nonzero.
+    pub objective_locked_door_count: usize,
+    // Number of locked doors (in addition to whatever `doors_mode`/`objective_locked_door_count`
+    // place) that are combat-locked: gated by clearing the destination room's enemies rather than
+    // by an item or objective. 0 disables this entirely.
+    // CAVEAT: same solver-enforcement gap as `objective_locked_door_count` above (see
+    // `DoorType::CombatLock`'s doc comment) — feature-gated off the same way.
+    pub combat_lock_door_count: usize,
+    // Number of `DoorType::MapRevealer` hatches to sprinkle in: cosmetic doors that lock nothing
+    // and don't affect logic (see `DoorType::MapRevealer` for what is and isn't emitted for
+    // them). 0 disables this.
+    pub map_revealer_door_count: usize,
+    // "Chaos doors": categories of door normally excluded from randomization (boss/miniboss gray
+    // doors, save/map/refill stations, item-tile-conflict doors, etc.) that the player has opted
+    // back into making lockable, for a much larger but less safely-curated pool of connections.
+    // Empty keeps the default (safe) exclusion set.
+    pub chaos_door_categories: Vec<DoorExclusionReason>,
     pub doors_mode: DoorsMode,
     pub start_location_mode: StartLocationMode,
     pub save_animals: SaveAnimals,
@@ -295,6 +384,50 @@ pub struct DifficultyConfig {
     // Debug:
     #[serde(skip_serializing_if = "Option::is_none")]
     pub debug_options: Option<DebugOptions>,
+    // Physics/lenience tuning (falls back to `PhysicsProfile::default()` when unset):
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physics_profile: Option<PhysicsProfile>,
+    // When set, repositioning heat-frame costs are chosen by sampling this many evenly spaced
+    // stopping points across the runway's admissible window instead of always assuming the
+    // worst case of using the whole window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monte_carlo_strat_samples: Option<usize>,
+    // Patch output options (consulted by the IPS patch manifest in `rust/src/patch.rs` to decide
+    // which optional patches to apply):
+    pub music_enabled: bool,
+    pub sound_effects_enabled: bool,
+    pub fast_reload: bool,
+}
+
+// Tunable constants for the run-speed/shortcharge physics math used throughout cross-room
+// requirement preprocessing. These were previously hard-coded magic numbers scattered across the
+// `get_come_in_*`/`get_cross_room_shortcharge_heat_frames` functions; collecting them here lets
+// romhack/alternate-physics seeds and calibration experiments adjust them without editing the
+// engine. Unset fields on `DifficultyConfig` fall back to these defaults.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct PhysicsProfile {
+    // Extra heat frames to account for run-up time before a shortcharge/speedball attempt.
+    pub startup_lenience_frames: Capacity,
+    // Extra heat frames to account for stopping on a dime and repositioning mid-runway.
+    pub reposition_lenience_frames: Capacity,
+    // Extra heat frames to account for inexactness in the heat frame calculations.
+    pub settle_lenience_frames: Capacity,
+    // Minimum number of frames a shinecharge can be assumed to take to reach full charge.
+    pub min_shinecharge_frames: Capacity,
+    // Maximum extra run speed achievable without Speed Booster upgrades factored in.
+    pub base_max_run_speed: f32,
+}
+
+impl Default for PhysicsProfile {
+    fn default() -> Self {
+        PhysicsProfile {
+            startup_lenience_frames: 20,
+            reposition_lenience_frames: 10,
+            settle_lenience_frames: 5,
+            min_shinecharge_frames: 85,
+            base_max_run_speed: 7.0,
+        }
+    }
 }
 
 // Includes preprocessing specific to the map:
@@ -306,6 +439,13 @@ pub struct Randomizer<'a> {
     pub difficulty_tiers: &'a [DifficultyConfig],
     pub base_links_data: &'a LinksDataGroup,
     pub seed_links_data: LinksDataGroup,
+    // One simplified seed-link set per entry of `difficulty_tiers`, each with `Requirement::Tech`/
+    // `Requirement::Strat` nodes folded against that tier's own tech/strat set. `find_hard_location`
+    // re-evaluates reachability at each tier in turn and must index into this instead of reusing
+    // `seed_links_data` (which is only valid for tier 0), or a tier's added tech/strats would be
+    // invisible: `simplify_links` permanently collapses unavailable `Requirement::Tech`/`Strat`
+    // nodes to `Requirement::Never`, so a link set baked for one tier can never recover them later.
+    pub seed_links_data_tiers: Vec<LinksDataGroup>,
     pub initial_items_remaining: Vec<usize>, // Corresponds to GameData.items_isv (one count per distinct item name)
 }
 
@@ -317,6 +457,12 @@ struct ItemLocationState {
     pub bireachable: bool,
     pub bireachable_vertex_id: Option<VertexId>,
     pub difficulty_tier: Option<usize>,
+    // Step on which this location first became bireachable, kept once set even though
+    // `bireachable` itself gets cleared and recomputed every call to `update_reachability`
+    // (see the comment there about the cost heuristic occasionally un-marking a vertex). Lets
+    // `get_spoiler_summary` report each location as newly reachable on only the one step it
+    // actually became so, instead of on every step afterward.
+    pub first_bireachable_step: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -325,12 +471,15 @@ struct FlagLocationState {
     pub reachable_vertex_id: Option<VertexId>,
     pub bireachable: bool,
     pub bireachable_vertex_id: Option<VertexId>,
+    pub first_reachable_step: Option<usize>,
+    pub first_bireachable_step: Option<usize>,
 }
 
 #[derive(Clone)]
 struct DoorState {
     pub bireachable: bool,
     pub bireachable_vertex_id: Option<VertexId>,
+    pub first_bireachable_step: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -345,6 +494,16 @@ struct DebugData {
     reverse: TraverseResult,
 }
 
+// A start location candidate's precomputed reachability, built by
+// `Randomizer::build_start_location_candidates` and used by `determine_start_location_ranked`.
+struct StartLocationCandidate {
+    start_loc: StartLocation,
+    global: GlobalState,
+    forward: TraverseResult,
+    forward0: TraverseResult,
+    reverse: TraverseResult,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum BeamType {
     Charge,
@@ -362,6 +521,30 @@ pub enum DoorType {
     Yellow,
     Gray,
     Beam(BeamType),
+    // Opens once the player has cleared `count` of `DifficultyConfig::objectives`. Unlike the
+    // other variants, this isn't unlocked by an item, so it needs its own logic requirement
+    // rather than one of the existing ammo/beam door requirements.
+    // FEATURE-GATED OFF: the traversal layer (`crate::traverse`) has no
+    // `Requirement::ObjectivesComplete(count)`-equivalent check against cleared-objective state to
+    // enforce this, so `ObjectiveDoorBuilder` refuses to place doors of this type (see its doc
+    // comment) and this variant is currently unreachable; it's kept so the spoiler/display code
+    // has a concrete type to name.
+    Objective { count: usize },
+    // A randomizer-placed gray door that, if enforced, would open once the enemies in the
+    // destination room's combat encounter are defeated, à la Zero Mission's
+    // `ConnectionLockHatchesWithTimer`/`ConnectionCheckHatchLockEvents`.
+    // FEATURE-GATED OFF: neither `crate::traverse` nor `crate::game_data` exposes a per-room
+    // enemy-clear requirement to AND into the entering `get_come_in_*` requirement, so doors of
+    // this type are never placed (see the `combat_lock_door_count` handling in `randomize_doors`)
+    // and this variant is currently unreachable; it's kept so the spoiler/display code has a
+    // concrete type to name.
+    CombatLock,
+    // Cosmetic hatch, named after Zero Mission's `DISPLAYS_ROOM_LOCATION` door flag. Never locks
+    // anything, so it's always `Requirement::Free` in logic. `randomize_doors` selects and records
+    // doors of this type (the spoiler lists them as "map-revealer"), but the ROM patching has no
+    // door-type-aware step, so nothing is written to the patched ROM for them and they reveal no
+    // map tiles in game.
+    MapRevealer,
 }
 
 #[derive(Clone, Copy)]
@@ -372,6 +555,45 @@ pub struct LockedDoor {
     pub bidirectional: bool, // if true, the door is locked on both sides, with a shared state
 }
 
+// Every item that can be a "key" gating some locked door in the minimum-unlock routing analysis
+// below (see `door_key_item`). Bounded to keep the 2^k key-subset search tractable; grows only if
+// `door_key_item` learns to recognize more unlock types.
+const DOOR_KEY_ITEMS: &[Item] = &[
+    Item::Missile,
+    Item::Super,
+    Item::PowerBomb,
+    Item::Charge,
+    Item::Ice,
+    Item::Wave,
+    Item::Spazer,
+    Item::Plasma,
+];
+
+// The single item that unlocks a door of this type, if any. Ammo-color doors use the same
+// color-to-item mapping as the vanilla game (red/green/yellow -> missile/super/power bomb); beam
+// doors use the matching beam item. Blue and map-revealer doors are never locked, and
+// gray/combat-lock/objective doors aren't gated by a single collectible (they're event- or
+// objective-gated), so they have no "key" in this analysis.
+fn door_key_item(door_type: DoorType) -> Option<Item> {
+    match door_type {
+        DoorType::Red => Some(Item::Missile),
+        DoorType::Green => Some(Item::Super),
+        DoorType::Yellow => Some(Item::PowerBomb),
+        DoorType::Beam(beam) => Some(match beam {
+            BeamType::Charge => Item::Charge,
+            BeamType::Ice => Item::Ice,
+            BeamType::Wave => Item::Wave,
+            BeamType::Spazer => Item::Spazer,
+            BeamType::Plasma => Item::Plasma,
+        }),
+        DoorType::Blue
+        | DoorType::Gray
+        | DoorType::CombatLock
+        | DoorType::MapRevealer
+        | DoorType::Objective { .. } => None,
+    }
+}
+
 #[derive(Clone)]
 // State that changes over the course of item placement attempts
 struct RandomizationState {
@@ -437,20 +659,6 @@ pub fn randomize_map_areas(map: &mut Map, seed: usize) {
     }
 }
 
-fn compute_run_frames(tiles: f32) -> Capacity {
-    assert!(tiles >= 0.0);
-    let frames = if tiles <= 7.0 {
-        9.0 + 4.0 * tiles
-    } else if tiles <= 16.0 {
-        15.0 + 3.0 * tiles
-    } else if tiles <= 42.0 {
-        32.0 + 2.0 * tiles
-    } else {
-        47.0 + 64.0 / 39.0 * tiles
-    };
-    frames.ceil() as Capacity
-}
-
 fn remove_some_duplicates<T: Clone + PartialEq + Eq + Hash>(
     x: &[T],
     dup_set: &HashSet<T>,
@@ -473,30 +681,23 @@ struct Preprocessor<'a> {
     game_data: &'a GameData,
     door_map: HashMap<(RoomId, NodeId), (RoomId, NodeId)>,
     difficulty: &'a DifficultyConfig,
+    physics_profile: PhysicsProfile,
 }
 
+// Frames spent running `other_runway_length` tiles, and frames spent running the remaining
+// `runway_length` tiles, of a continuous combined run across both rooms from a standstill.
+// Previously this assumed a fixed 85-frame shinecharge duration and a constant-acceleration
+// closed-form approximation for runways too short to hold a dash the whole time; both legs are
+// now taken directly from the frame-stepped `run_speed` integrator, so the split is exact for any
+// combined runway length rather than only for the "dash held the whole time" case.
 fn compute_shinecharge_frames(
     other_runway_length: f32,
     runway_length: f32,
 ) -> (Capacity, Capacity) {
     let combined_length = other_runway_length + runway_length;
-    if combined_length > 31.3 {
-        // Dash can be held the whole time:
-        let total_time = compute_run_frames(combined_length);
-        let other_time = compute_run_frames(other_runway_length);
-        return (other_time, total_time - other_time);
-    }
-    // Combined runway is too short to hold dash the whole time. A shortcharge is needed:
-    let total_time = 85.0; // 85 frames to charge a shinespark (assuming a good enough 1-tap)
-    let initial_speed = 0.125;
-    let acceleration =
-        2.0 * (combined_length - initial_speed * total_time) / (total_time * total_time);
-    let other_time =
-        (f32::sqrt(initial_speed * initial_speed + 2.0 * acceleration * other_runway_length)
-            - initial_speed)
-            / acceleration;
-    let other_time = other_time.ceil() as Capacity;
-    (other_time, total_time as Capacity - other_time)
+    let (_, total_frames) = speed_and_frames_for_runway(combined_length);
+    let (_, other_frames) = speed_and_frames_for_runway(other_runway_length);
+    (other_frames, total_frames - other_frames)
 }
 
 impl<'a> Preprocessor<'a> {
@@ -521,10 +722,17 @@ impl<'a> Preprocessor<'a> {
                 door_map.insert((32, 8), (dst_room_id, dst_node_id));
             }
         }
+        debug_assert!(
+            transition_registry::unclaimed_exit_conditions().is_empty(),
+            "ExitCondition variant(s) {:?} are not claimed by any entrance handler in transition_registry",
+            transition_registry::unclaimed_exit_conditions()
+        );
+
         Preprocessor {
             game_data,
             door_map,
             difficulty,
+            physics_profile: difficulty.physics_profile.unwrap_or_default(),
         }
     }
 
@@ -589,6 +797,15 @@ impl<'a> Preprocessor<'a> {
                 //     );
                 // }
                 if let Some(req) = req_opt {
+                    let mut strat_notes = vec![];
+                    if self
+                        .difficulty
+                        .debug_options
+                        .as_ref()
+                        .is_some_and(|d| d.strat_explanations)
+                    {
+                        self.explain_requirement(&req, &mut strat_notes);
+                    }
                     door_links.push(Link {
                         from_vertex_id: *src_vertex_id,
                         to_vertex_id: *dst_vertex_id,
@@ -597,7 +814,7 @@ impl<'a> Preprocessor<'a> {
                         end_with_shinecharge: carry_shinecharge,
                         notable_strat_name: None,
                         strat_name: "Base (Cross Room)".to_string(),
-                        strat_notes: vec![],
+                        strat_notes,
                     });
                 }
             }
@@ -649,6 +866,42 @@ impl<'a> Preprocessor<'a> {
         door_links
     }
 
+    // Recursively resolves a `Requirement` tree into human-readable notes (tech/item display
+    // names, numeric costs), so a door strat's logic can be explained without having to read the
+    // flattened tree by hand. Only the variants that actually appear in the `get_come_in_*`
+    // builders below are given a dedicated explanation; anything else falls back to its debug
+    // representation.
+    fn explain_requirement(&self, req: &Requirement, notes: &mut Vec<String>) {
+        match req {
+            Requirement::Free => {}
+            Requirement::Never => notes.push("impossible".to_string()),
+            Requirement::Tech(idx) => notes.push(format!("needs {}", self.game_data.tech_isv.keys[*idx])),
+            Requirement::Strat(idx) => {
+                notes.push(format!("needs strat {}", self.game_data.notable_strat_isv.keys[*idx]))
+            }
+            Requirement::Item(idx) => notes.push(format!("needs {}", Item::VARIANTS[*idx])),
+            Requirement::HeatFrames(frames) => notes.push(format!("{} heat frames", frames)),
+            Requirement::ShineChargeFrames(frames) => {
+                notes.push(format!("{} shinecharge frames", frames))
+            }
+            Requirement::And(subreqs) => {
+                for r in subreqs {
+                    self.explain_requirement(r, notes);
+                }
+            }
+            Requirement::Or(subreqs) => {
+                let mut alt_notes = vec![];
+                for r in subreqs {
+                    let mut sub_notes = vec![];
+                    self.explain_requirement(r, &mut sub_notes);
+                    alt_notes.push(sub_notes.join(" and "));
+                }
+                notes.push(format!("one of: [{}]", alt_notes.join("] or [")));
+            }
+            other => notes.push(format!("{:?}", other)),
+        }
+    }
+
     fn get_cross_room_reqs(
         &self,
         exit_condition: &ExitCondition,
@@ -853,6 +1106,26 @@ impl<'a> Preprocessor<'a> {
         }
     }
 
+    // Heat-frame cost of running to some point within `[min_tiles, max_tiles]` of a `total_tiles`
+    // runway, repositioning, then running the rest. Falls back to the worst-case assumption of
+    // stopping at `max_tiles` unless `monte_carlo_strat_samples` is configured.
+    fn reposition_heat_frames(&self, total_tiles: f32, min_tiles: f32, max_tiles: f32) -> Capacity {
+        match self.difficulty.monte_carlo_strat_samples {
+            Some(samples) if samples > 1 => min_cost_reposition_frames(
+                total_tiles,
+                min_tiles,
+                max_tiles,
+                self.physics_profile.reposition_lenience_frames,
+                samples,
+            ),
+            _ => {
+                compute_run_frames(total_tiles - max_tiles)
+                    + compute_run_frames(max_tiles)
+                    + self.physics_profile.reposition_lenience_frames
+            }
+        }
+    }
+
     fn get_come_in_normally_reqs(&self, exit_condition: &ExitCondition) -> Option<Requirement> {
         match exit_condition {
             ExitCondition::LeaveNormally {} => Some(Requirement::Free),
@@ -893,13 +1166,10 @@ impl<'a> Preprocessor<'a> {
                 }
                 if *heated {
                     let heat_frames = if *from_exit_node {
-                        compute_run_frames(min_tiles) * 2 + 20
+                        round_trip_run_frames(min_tiles) + self.physics_profile.startup_lenience_frames
                     } else {
                         if effective_length > max_tiles {
-                            // 10 heat frames to position after stopping on a dime, before resuming running
-                            compute_run_frames(effective_length - max_tiles)
-                                + compute_run_frames(max_tiles)
-                                + 10
+                            self.reposition_heat_frames(effective_length, min_tiles, max_tiles)
                         } else {
                             compute_run_frames(effective_length)
                         }
@@ -963,32 +1233,38 @@ impl<'a> Preprocessor<'a> {
         if from_exit_node {
             // Runway in the exiting room starts and ends at the door so we need to run both directions:
             if entrance_heated && exit_heated {
-                // Both rooms are heated. Heat frames are optimized by minimizing runway usage in the source room.
-                // But since the shortcharge difficulty is not known here, we conservatively assume up to 33 tiles
-                // of the combined runway may need to be used. (TODO: Instead add a Requirement enum case to handle this more accurately.)
-                let other_runway_length =
-                    f32::max(0.0, f32::min(exit_length, 33.0 - entrance_length));
-                let heat_frames_1 = compute_run_frames(other_runway_length) + 20;
+                // Both rooms are heated. Heat frames are optimized by minimizing runway usage in the source room:
+                // of the `heated_shine_charge_tiles` needed for the shortcharge, put as much as possible in the
+                // (already-heated) entrance room, and only spend the remainder in the exit room.
+                let shortcharge_tiles = self.difficulty.heated_shine_charge_tiles;
+                let other_runway_length = f32::max(
+                    0.0,
+                    f32::min(exit_length, shortcharge_tiles - entrance_length),
+                );
+                let heat_frames_1 = compute_run_frames(other_runway_length) + self.physics_profile.startup_lenience_frames;
                 let heat_frames_2 = Capacity::max(
-                    85,
+                    self.physics_profile.min_shinecharge_frames,
                     compute_run_frames(other_runway_length + entrance_length),
                 );
-                // Add 5 lenience frames (partly to account for the possibility of some inexactness in our calculations)
-                total_heat_frames += heat_frames_1 + heat_frames_2 + 5;
+                // Add lenience frames (partly to account for the possibility of some inexactness in our calculations)
+                total_heat_frames += heat_frames_1 + heat_frames_2 + self.physics_profile.settle_lenience_frames;
             } else if !entrance_heated && exit_heated {
                 // Only the destination room is heated. Heat frames are optimized by using the full runway in
                 // the source room.
                 let (_, heat_frames) = compute_shinecharge_frames(exit_length, entrance_length);
-                total_heat_frames += heat_frames + 5;
+                total_heat_frames += heat_frames + self.physics_profile.settle_lenience_frames;
             } else if entrance_heated && !exit_heated {
                 // Only the source room is heated. As in the first case above, heat frames are optimized by
-                // minimizing runway usage in the source room. (TODO: Use new Requirement enum case.)
-                let other_runway_length =
-                    f32::max(0.0, f32::min(exit_length, 33.0 - entrance_length));
-                let heat_frames_1 = compute_run_frames(other_runway_length) + 20;
+                // minimizing runway usage in the (heated) source room.
+                let shortcharge_tiles = self.difficulty.heated_shine_charge_tiles;
+                let other_runway_length = f32::max(
+                    0.0,
+                    f32::min(exit_length, shortcharge_tiles - entrance_length),
+                );
+                let heat_frames_1 = compute_run_frames(other_runway_length) + self.physics_profile.startup_lenience_frames;
                 let (heat_frames_2, _) =
                     compute_shinecharge_frames(other_runway_length, entrance_length);
-                total_heat_frames += heat_frames_1 + heat_frames_2 + 5;
+                total_heat_frames += heat_frames_1 + heat_frames_2 + self.physics_profile.settle_lenience_frames;
             }
         } else if entrance_heated || exit_heated {
             // Runway in the other room starts at a different node and runs toward the door. The full combined
@@ -1089,7 +1365,7 @@ impl<'a> Preprocessor<'a> {
                 if !self.add_run_speed_reqs(
                     combined_runway_length,
                     0.0,
-                    7.0,
+                    self.physics_profile.base_max_run_speed,
                     *heated || runway_heated,
                     min_extra_run_speed,
                     max_extra_run_speed,
@@ -1196,14 +1472,14 @@ impl<'a> Preprocessor<'a> {
                 let mut reqs: Vec<Requirement> = vec![Requirement::Tech(
                     self.game_data.tech_isv.index_by_key["canSpeedball"],
                 )];
+                if *physics != Some(Physics::Air) {
+                    reqs.push(Requirement::Item(Item::Gravity as ItemId));
+                }
                 let combined_runway_length = effective_length + runway_length;
                 reqs.push(Requirement::SpeedBall {
                     used_tiles: Float::new(combined_runway_length),
                     heated: *heated || runway_heated,
                 });
-                if *physics != Some(Physics::Air) {
-                    reqs.push(Requirement::Item(Item::Gravity as ItemId));
-                }
                 if *heated || runway_heated {
                     // Speedball would technically have slightly different heat frames (compared to a shortcharge) since you no longer
                     // gaining run speed while in the air, but this is a small enough difference to neglect for now. There should be
@@ -1280,13 +1556,14 @@ impl<'a> Preprocessor<'a> {
 
                 if *heated {
                     let heat_frames = if *from_exit_node {
-                        compute_run_frames(min_tiles + unusable_tiles) * 2 + 20
+                        round_trip_run_frames(min_tiles + unusable_tiles) + self.physics_profile.startup_lenience_frames
                     } else {
                         if max_tiles < effective_length - unusable_tiles {
-                            // 10 heat frames to position after stopping on a dime, before resuming running
-                            compute_run_frames(effective_length - unusable_tiles - max_tiles)
-                                + compute_run_frames(max_tiles + unusable_tiles)
-                                + 10
+                            self.reposition_heat_frames(
+                                effective_length,
+                                min_tiles + unusable_tiles,
+                                max_tiles + unusable_tiles,
+                            )
                         } else {
                             compute_run_frames(effective_length)
                         }
@@ -1347,7 +1624,7 @@ impl<'a> Preprocessor<'a> {
                 if !self.add_run_speed_reqs(
                     effective_length,
                     0.0,
-                    7.0,
+                    self.physics_profile.base_max_run_speed,
                     *heated,
                     entrance_min_extra_run_speed,
                     entrance_max_extra_run_speed,
@@ -1366,19 +1643,25 @@ impl<'a> Preprocessor<'a> {
                 if *from_exit_node {
                     // Runway in the other room starts and ends at the door so we need to run both directions:
                     if *heated {
-                        // Shortcharge difficulty is not known here, so we conservatively assume up to 33 tiles
-                        // of runway may need to be used. (TODO: Instead add a Requirement enum case to handle this more accurately.)
-                        let other_runway_length = f32::min(effective_length, 33.0 + unusable_tiles);
-                        let heat_frames_1 = compute_run_frames(other_runway_length) + 20;
+                        // Use the configured minimum shortcharge runway rather than a hardcoded guess, so a
+                        // tech-lenient preset (smaller `heated_shine_charge_tiles`) isn't charged for more heat
+                        // frames than it actually needs.
+                        // TODO: This is still a preprocessing-time approximation. A `ShinechargeRunway`
+                        // Requirement case that carries the raw runway geometry and gets resolved against the
+                        // concrete skill context at evaluation time would let this be exact instead of
+                        // worst-case, but that depends on a Requirement enum case we don't have here.
+                        let other_runway_length =
+                            f32::min(effective_length, self.difficulty.heated_shine_charge_tiles + unusable_tiles);
+                        let heat_frames_1 = compute_run_frames(other_runway_length) + self.physics_profile.startup_lenience_frames;
                         let (heat_frames_2, _) =
                             compute_shinecharge_frames(other_runway_length, 0.0);
-                        reqs.push(Requirement::HeatFrames(heat_frames_1 + heat_frames_2 + 5));
+                        reqs.push(Requirement::HeatFrames(heat_frames_1 + heat_frames_2 + self.physics_profile.settle_lenience_frames));
                     }
                 } else if *heated {
                     // Runway in the other room starts at a different node and runs toward the door. The full combined
                     // runway is used.
                     let (frames_1, _) = compute_shinecharge_frames(effective_length, 0.0);
-                    let heat_frames = frames_1 + 5;
+                    let heat_frames = frames_1 + self.physics_profile.settle_lenience_frames;
                     reqs.push(Requirement::HeatFrames(heat_frames));
                 }
                 Some(Requirement::make_and(reqs))
@@ -1435,7 +1718,7 @@ impl<'a> Preprocessor<'a> {
                 }
                 if *heated {
                     let heat_frames = if *from_exit_node {
-                        compute_run_frames(adjacent_min_tiles) * 2 + 20
+                        round_trip_run_frames(adjacent_min_tiles) + self.physics_profile.startup_lenience_frames
                     } else {
                         compute_run_frames(effective_length)
                     };
@@ -1550,7 +1833,7 @@ impl<'a> Preprocessor<'a> {
                 }
                 if *heated {
                     let heat_frames = if *from_exit_node {
-                        compute_run_frames(adjacent_min_tiles) * 2 + 20
+                        round_trip_run_frames(adjacent_min_tiles) + self.physics_profile.startup_lenience_frames
                     } else {
                         compute_run_frames(effective_length)
                     };
@@ -1689,7 +1972,7 @@ impl<'a> Preprocessor<'a> {
                 if !self.add_run_speed_reqs(
                     effective_length,
                     0.0,
-                    7.0,
+                    self.physics_profile.base_max_run_speed,
                     *heated,
                     entrance_min_extra_run_speed,
                     entrance_max_extra_run_speed,
@@ -1704,7 +1987,7 @@ impl<'a> Preprocessor<'a> {
                 if *heated {
                     let heat_frames = if *from_exit_node {
                         // For now, be conservative by assuming we use the whole runway. This could be refined later:
-                        compute_run_frames(effective_length) * 2 + 20
+                        round_trip_run_frames(effective_length) + self.physics_profile.startup_lenience_frames
                     } else {
                         compute_run_frames(effective_length)
                     };
@@ -1747,12 +2030,12 @@ impl<'a> Preprocessor<'a> {
                     if *from_exit_node {
                         let runway_length = f32::min(33.0, effective_length);
                         let run_frames = compute_run_frames(runway_length);
-                        let heat_frames_1 = run_frames + 20;
-                        let heat_frames_2 = Capacity::max(85, run_frames);
+                        let heat_frames_1 = run_frames + self.physics_profile.startup_lenience_frames;
+                        let heat_frames_2 = Capacity::max(self.physics_profile.min_shinecharge_frames, run_frames);
                         reqs.push(Requirement::HeatFrames(heat_frames_1 + heat_frames_2 + 15));
                     } else {
-                        let heat_frames = Capacity::max(85, compute_run_frames(effective_length));
-                        reqs.push(Requirement::HeatFrames(heat_frames + 5));
+                        let heat_frames = Capacity::max(self.physics_profile.min_shinecharge_frames, compute_run_frames(effective_length));
+                        reqs.push(Requirement::HeatFrames(heat_frames + self.physics_profile.settle_lenience_frames));
                     }
                 }
                 Some(Requirement::make_and(reqs))
@@ -1789,12 +2072,12 @@ impl<'a> Preprocessor<'a> {
                     if *from_exit_node {
                         let runway_length = f32::min(33.0, effective_length);
                         let run_frames = compute_run_frames(runway_length);
-                        let heat_frames_1 = run_frames + 20;
-                        let heat_frames_2 = Capacity::max(85, run_frames);
+                        let heat_frames_1 = run_frames + self.physics_profile.startup_lenience_frames;
+                        let heat_frames_2 = Capacity::max(self.physics_profile.min_shinecharge_frames, run_frames);
                         reqs.push(Requirement::HeatFrames(heat_frames_1 + heat_frames_2 + 15));
                     } else {
-                        let heat_frames = Capacity::max(85, compute_run_frames(effective_length));
-                        reqs.push(Requirement::HeatFrames(heat_frames + 5));
+                        let heat_frames = Capacity::max(self.physics_profile.min_shinecharge_frames, compute_run_frames(effective_length));
+                        reqs.push(Requirement::HeatFrames(heat_frames + self.physics_profile.settle_lenience_frames));
                     }
                 }
                 Some(Requirement::make_and(reqs))
@@ -1829,7 +2112,7 @@ impl<'a> Preprocessor<'a> {
                 reqs.push(Requirement::Item(Item::SpeedBooster as ItemId));
                 if *heated {
                     let heat_frames = if *from_exit_node {
-                        compute_run_frames(min_tiles) * 2 + 20
+                        round_trip_run_frames(min_tiles) + self.physics_profile.startup_lenience_frames
                     } else {
                         compute_run_frames(effective_length)
                     };
@@ -1877,12 +2160,12 @@ impl<'a> Preprocessor<'a> {
                     if *from_exit_node {
                         let runway_length = f32::min(33.0, effective_length);
                         let run_frames = compute_run_frames(runway_length);
-                        let heat_frames_1 = run_frames + 20;
-                        let heat_frames_2 = Capacity::max(85, run_frames);
-                        reqs.push(Requirement::HeatFrames(heat_frames_1 + heat_frames_2 + 5));
+                        let heat_frames_1 = run_frames + self.physics_profile.startup_lenience_frames;
+                        let heat_frames_2 = Capacity::max(self.physics_profile.min_shinecharge_frames, run_frames);
+                        reqs.push(Requirement::HeatFrames(heat_frames_1 + heat_frames_2 + self.physics_profile.settle_lenience_frames));
                     } else {
-                        let heat_frames = Capacity::max(85, compute_run_frames(effective_length));
-                        reqs.push(Requirement::HeatFrames(heat_frames + 5));
+                        let heat_frames = Capacity::max(self.physics_profile.min_shinecharge_frames, compute_run_frames(effective_length));
+                        reqs.push(Requirement::HeatFrames(heat_frames + self.physics_profile.settle_lenience_frames));
                     }
                 }
                 Some(Requirement::make_and(reqs))
@@ -1930,15 +2213,15 @@ impl<'a> Preprocessor<'a> {
                     if *from_exit_node {
                         let runway_length = f32::min(33.0, effective_length);
                         let run_frames = compute_run_frames(runway_length);
-                        let heat_frames_1 = run_frames + 20;
-                        let heat_frames_2 = Capacity::max(85, run_frames);
+                        let heat_frames_1 = run_frames + self.physics_profile.startup_lenience_frames;
+                        let heat_frames_2 = Capacity::max(self.physics_profile.min_shinecharge_frames, run_frames);
                         reqs.push(Requirement::HeatFrames(
                             heat_frames_1 + heat_frames_2 + heat_frames_temp_blue + 15,
                         ));
                     } else {
-                        let heat_frames = Capacity::max(85, compute_run_frames(effective_length));
+                        let heat_frames = Capacity::max(self.physics_profile.min_shinecharge_frames, compute_run_frames(effective_length));
                         reqs.push(Requirement::HeatFrames(
-                            heat_frames + heat_frames_temp_blue + 5,
+                            heat_frames + heat_frames_temp_blue + self.physics_profile.settle_lenience_frames,
                         ));
                     }
                 }
@@ -2275,152 +2558,180 @@ impl<'a> Preprocessor<'a> {
     }
 }
 
+// Why a door is normally excluded from randomization, so `DifficultyConfig::chaos_door_categories`
+// can selectively re-enable categories of it instead of only having a single all-or-nothing
+// blacklist. Kept local to this module rather than in `crate::game_data` since that's where the
+// door ptr pairs themselves are sourced from and this data doesn't yet have anywhere else to live.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DoorExclusionReason {
+    // Vanilla gray doors gating a boss, miniboss, or space pirate encounter.
+    Boss,
+    // Save, map, and energy/ammo refill stations.
+    Station,
+    // The two doors inside Pants Room, which share a single room segment.
+    PantsInterior,
+    // Doors where randomizing would create a visual conflict in map tiles between a disappearing
+    // door and a disappearing item (or objective marker).
+    ItemTileConflict,
+}
+
+// Doors which we do not want to randomize by default, tagged with why. `get_randomizable_doors`
+// excludes everything here except categories the player has opted back into via
+// `DifficultyConfig::chaos_door_categories`.
+const NON_RANDOMIZABLE_DOORS: &[(usize, usize, DoorExclusionReason)] = &[
+    // Gray doors - Pirate rooms:
+    (0x18B7A, 0x18B62, DoorExclusionReason::Boss), // Pit Room left
+    (0x18B86, 0x18B92, DoorExclusionReason::Boss), // Pit Room right
+    (0x19192, 0x1917A, DoorExclusionReason::Boss), // Baby Kraid left
+    (0x1919E, 0x191AA, DoorExclusionReason::Boss), // Baby Kraid right
+    (0x1A558, 0x1A54C, DoorExclusionReason::Boss), // Plasma Room
+    (0x19A32, 0x19966, DoorExclusionReason::Boss), // Metal Pirates left
+    (0x19A3E, 0x19A1A, DoorExclusionReason::Boss), // Metal Pirates right
+    // Gray doors - Bosses:
+    (0x191CE, 0x191B6, DoorExclusionReason::Boss), // Kraid left
+    (0x191DA, 0x19252, DoorExclusionReason::Boss), // Kraid right
+    (0x1A2C4, 0x1A2AC, DoorExclusionReason::Boss), // Phantoon
+    (0x1A978, 0x1A924, DoorExclusionReason::Boss), // Draygon left
+    (0x1A96C, 0x1A840, DoorExclusionReason::Boss), // Draygon right
+    (0x198B2, 0x19A62, DoorExclusionReason::Boss), // Ridley left
+    (0x198BE, 0x198CA, DoorExclusionReason::Boss), // Ridley right
+    (0x1AA8C, 0x1AAE0, DoorExclusionReason::Boss), // Mother Brain left
+    (0x1AA80, 0x1AAC8, DoorExclusionReason::Boss), // Mother Brain right
+    // Gray doors - Minibosses:
+    (0x18BAA, 0x18BC2, DoorExclusionReason::Boss), // Bomb Torizo
+    (0x18E56, 0x18E3E, DoorExclusionReason::Boss), // Spore Spawn bottom
+    (0x193EA, 0x193D2, DoorExclusionReason::Boss), // Crocomire top
+    (0x1A90C, 0x1A774, DoorExclusionReason::Boss), // Botwoon left
+    (0x19882, 0x19A86, DoorExclusionReason::Boss), // Golden Torizo right
+    // Save stations:
+    (0x189BE, 0x1899A, DoorExclusionReason::Station), // Crateria Save Room
+    (0x19006, 0x18D12, DoorExclusionReason::Station), // Green Brinstar Main Shaft Save Room
+    (0x19012, 0x18F52, DoorExclusionReason::Station), // Etecoon Save Room
+    (0x18FD6, 0x18DF6, DoorExclusionReason::Station), // Big Pink Save Room
+    (0x1926A, 0x190D2, DoorExclusionReason::Station), // Caterpillar Save Room
+    (0x1925E, 0x19186, DoorExclusionReason::Station), // Warehouse Save Room
+    (0x1A828, 0x1A744, DoorExclusionReason::Station), // Aqueduct Save Room
+    (0x1A888, 0x1A7EC, DoorExclusionReason::Station), // Draygon Save Room left
+    (0x1A87C, 0x1A930, DoorExclusionReason::Station), // Draygon Save Room right
+    (0x1A5F4, 0x1A588, DoorExclusionReason::Station), // Forgotten Highway Save Room
+    (0x1A324, 0x1A354, DoorExclusionReason::Station), // Glass Tunnel Save Room
+    (0x19822, 0x193BA, DoorExclusionReason::Station), // Crocomire Save Room
+    (0x19462, 0x19456, DoorExclusionReason::Station), // Post Crocomire Save Room
+    (0x1982E, 0x19702, DoorExclusionReason::Station), // Lower Norfair Elevator Save Room
+    (0x19816, 0x192FA, DoorExclusionReason::Station), // Frog Savestation left
+    (0x1980A, 0x197DA, DoorExclusionReason::Station), // Frog Savestation right
+    (0x197CE, 0x1959A, DoorExclusionReason::Station), // Bubble Mountain Save Room
+    (0x19AB6, 0x19A0E, DoorExclusionReason::Station), // Red Kihunter Shaft Save Room
+    (0x1A318, 0x1A240, DoorExclusionReason::Station), // Wrecked Ship Save Room
+    (0x1AAD4, 0x1AABC, DoorExclusionReason::Station), // Lower Tourian Save Room
+    // Map stations:
+    (0x18C2E, 0x18BDA, DoorExclusionReason::Station), // Crateria Map Room
+    (0x18D72, 0x18D36, DoorExclusionReason::Station), // Brinstar Map Room
+    (0x197C2, 0x19306, DoorExclusionReason::Station), // Norfair Map Room
+    (0x1A5E8, 0x1A51C, DoorExclusionReason::Station), // Maridia Map Room
+    (0x1A2B8, 0x1A2A0, DoorExclusionReason::Station), // Wrecked Ship Map Room
+    (0x1AB40, 0x1A99C, DoorExclusionReason::Station), // Tourian Map Room (Upper Tourian Save Room)
+    // Refill stations:
+    (0x18D96, 0x18D7E, DoorExclusionReason::Station), // Green Brinstar Missile Refill Room
+    (0x18F6A, 0x18DBA, DoorExclusionReason::Station), // Dachora Energy Refill Room
+    (0x191FE, 0x1904E, DoorExclusionReason::Station), // Sloaters Refill
+    (0x1A894, 0x1A8F4, DoorExclusionReason::Station), // Maridia Missile Refill Room
+    (0x1A930, 0x1A87C, DoorExclusionReason::Station), // Maridia Health Refill Room
+    (0x19786, 0x19756, DoorExclusionReason::Station), // Nutella Refill left
+    (0x19792, 0x1976E, DoorExclusionReason::Station), // Nutella Refill right
+    (0x1920A, 0x191C2, DoorExclusionReason::Station), // Kraid Recharge Station
+    (0x198A6, 0x19A7A, DoorExclusionReason::Station), // Golden Torizo Energy Recharge
+    (0x1AA74, 0x1AA68, DoorExclusionReason::Station), // Tourian Recharge Room
+    // Pants room interior door
+    (0x1A7A4, 0x1A78C, DoorExclusionReason::PantsInterior), // Left door
+    (0x1A78C, 0x1A7A4, DoorExclusionReason::PantsInterior), // Right door
+    // Items: (to avoid an interaction in map tiles between doors disappearing and items disappearing)
+    (0x18FA6, 0x18EDA, DoorExclusionReason::ItemTileConflict), // First Missile Room
+    (0x18FFA, 0x18FEE, DoorExclusionReason::ItemTileConflict), // Billy Mays Room
+    (0x18D66, 0x18D5A, DoorExclusionReason::ItemTileConflict), // Brinstar Reserve Tank Room
+    (0x18F3A, 0x18F5E, DoorExclusionReason::ItemTileConflict), // Etecoon Energy Tank Room (top left door)
+    (0x18F5E, 0x18F3A, DoorExclusionReason::ItemTileConflict), // Etecoon Supers Room
+    (0x18E02, 0x18E62, DoorExclusionReason::ItemTileConflict), // Big Pink (top door to Pink Brinstar Power Bomb Room)
+    (0x18FCA, 0x18FBE, DoorExclusionReason::ItemTileConflict), // Hopper Energy Tank Room
+    (0x19132, 0x19126, DoorExclusionReason::ItemTileConflict), // Spazer Room
+    (0x19162, 0x1914A, DoorExclusionReason::ItemTileConflict), // Warehouse Energy Tank Room
+    (0x19252, 0x191DA, DoorExclusionReason::ItemTileConflict), // Varia Suit Room
+    (0x18ADE, 0x18A36, DoorExclusionReason::ItemTileConflict), // The Moat (left door)
+    (0x18C9A, 0x18C82, DoorExclusionReason::ItemTileConflict), // The Final Missile
+    (0x18BE6, 0x18C3A, DoorExclusionReason::ItemTileConflict), // Terminator Room (left door)
+    (0x18B0E, 0x18952, DoorExclusionReason::ItemTileConflict), // Gauntlet Energy Tank Room (right door)
+    (0x1A924, 0x1A978, DoorExclusionReason::ItemTileConflict), // Space Jump Room
+    (0x19A62, 0x198B2, DoorExclusionReason::ItemTileConflict), // Ridley Tank Room
+    (0x199D2, 0x19A9E, DoorExclusionReason::ItemTileConflict), // Lower Norfair Escape Power Bomb Room (left door)
+    (0x199DE, 0x199C6, DoorExclusionReason::ItemTileConflict), // Lower Norfair Escape Power Bomb Room (top door)
+    (0x19876, 0x1983A, DoorExclusionReason::ItemTileConflict), // Golden Torizo's Room (left door)
+    (0x19A86, 0x19882, DoorExclusionReason::ItemTileConflict), // Screw Attack Room (left door)
+    (0x1941A, 0x192D6, DoorExclusionReason::ItemTileConflict), // Hi Jump Energy Tank Room (right door)
+    (0x193F6, 0x19426, DoorExclusionReason::ItemTileConflict), // Hi Jump Boots Room
+    (0x1929A, 0x19732, DoorExclusionReason::ItemTileConflict), // Cathedral (right door)
+    (0x1953A, 0x19552, DoorExclusionReason::ItemTileConflict), // Green Bubbles Missile Room
+    (0x195B2, 0x195BE, DoorExclusionReason::ItemTileConflict), // Speed Booster Hall
+    (0x195BE, 0x195B2, DoorExclusionReason::ItemTileConflict), // Speed Booster Room
+    (0x1962A, 0x1961E, DoorExclusionReason::ItemTileConflict), // Wave Beam Room
+    (0x1935A, 0x1937E, DoorExclusionReason::ItemTileConflict), // Ice Beam Room
+    (0x1938A, 0x19336, DoorExclusionReason::ItemTileConflict), // Crumble Shaft (top right door)
+    (0x19402, 0x192E2, DoorExclusionReason::ItemTileConflict), // Crocomire Escape (left door)
+    (0x1946E, 0x1943E, DoorExclusionReason::ItemTileConflict), // Post Crocomire Power Bomb Room
+    (0x19516, 0x194DA, DoorExclusionReason::ItemTileConflict), // Grapple Beam Room (bottom right door)
+    (0x1A2E8, 0x1A210, DoorExclusionReason::ItemTileConflict), // Wrecked Ship West Super Room
+    (0x1A300, 0x18A06, DoorExclusionReason::ItemTileConflict), // Gravity Suit Room (left door)
+    (0x1A30C, 0x1A1A4, DoorExclusionReason::ItemTileConflict), // Gravity Suit Room (right door)
+];
+
 fn get_randomizable_doors(
     game_data: &GameData,
     difficulty: &DifficultyConfig,
 ) -> HashSet<DoorPtrPair> {
-    // Doors which we do not want to randomize:
-    let mut non_randomizable_doors: HashSet<DoorPtrPair> = vec![
-        // Gray doors - Pirate rooms:
-        (0x18B7A, 0x18B62), // Pit Room left
-        (0x18B86, 0x18B92), // Pit Room right
-        (0x19192, 0x1917A), // Baby Kraid left
-        (0x1919E, 0x191AA), // Baby Kraid right
-        (0x1A558, 0x1A54C), // Plasma Room
-        (0x19A32, 0x19966), // Metal Pirates left
-        (0x19A3E, 0x19A1A), // Metal Pirates right
-        // Gray doors - Bosses:
-        (0x191CE, 0x191B6), // Kraid left
-        (0x191DA, 0x19252), // Kraid right
-        (0x1A2C4, 0x1A2AC), // Phantoon
-        (0x1A978, 0x1A924), // Draygon left
-        (0x1A96C, 0x1A840), // Draygon right
-        (0x198B2, 0x19A62), // Ridley left
-        (0x198BE, 0x198CA), // Ridley right
-        (0x1AA8C, 0x1AAE0), // Mother Brain left
-        (0x1AA80, 0x1AAC8), // Mother Brain right
-        // Gray doors - Minibosses:
-        (0x18BAA, 0x18BC2), // Bomb Torizo
-        (0x18E56, 0x18E3E), // Spore Spawn bottom
-        (0x193EA, 0x193D2), // Crocomire top
-        (0x1A90C, 0x1A774), // Botwoon left
-        (0x19882, 0x19A86), // Golden Torizo right
-        // Save stations:
-        (0x189BE, 0x1899A), // Crateria Save Room
-        (0x19006, 0x18D12), // Green Brinstar Main Shaft Save Room
-        (0x19012, 0x18F52), // Etecoon Save Room
-        (0x18FD6, 0x18DF6), // Big Pink Save Room
-        (0x1926A, 0x190D2), // Caterpillar Save Room
-        (0x1925E, 0x19186), // Warehouse Save Room
-        (0x1A828, 0x1A744), // Aqueduct Save Room
-        (0x1A888, 0x1A7EC), // Draygon Save Room left
-        (0x1A87C, 0x1A930), // Draygon Save Room right
-        (0x1A5F4, 0x1A588), // Forgotten Highway Save Room
-        (0x1A324, 0x1A354), // Glass Tunnel Save Room
-        (0x19822, 0x193BA), // Crocomire Save Room
-        (0x19462, 0x19456), // Post Crocomire Save Room
-        (0x1982E, 0x19702), // Lower Norfair Elevator Save Room
-        (0x19816, 0x192FA), // Frog Savestation left
-        (0x1980A, 0x197DA), // Frog Savestation right
-        (0x197CE, 0x1959A), // Bubble Mountain Save Room
-        (0x19AB6, 0x19A0E), // Red Kihunter Shaft Save Room
-        (0x1A318, 0x1A240), // Wrecked Ship Save Room
-        (0x1AAD4, 0x1AABC), // Lower Tourian Save Room
-        // Map stations:
-        (0x18C2E, 0x18BDA), // Crateria Map Room
-        (0x18D72, 0x18D36), // Brinstar Map Room
-        (0x197C2, 0x19306), // Norfair Map Room
-        (0x1A5E8, 0x1A51C), // Maridia Map Room
-        (0x1A2B8, 0x1A2A0), // Wrecked Ship Map Room
-        (0x1AB40, 0x1A99C), // Tourian Map Room (Upper Tourian Save Room)
-        // Refill stations:
-        (0x18D96, 0x18D7E), // Green Brinstar Missile Refill Room
-        (0x18F6A, 0x18DBA), // Dachora Energy Refill Room
-        (0x191FE, 0x1904E), // Sloaters Refill
-        (0x1A894, 0x1A8F4), // Maridia Missile Refill Room
-        (0x1A930, 0x1A87C), // Maridia Health Refill Room
-        (0x19786, 0x19756), // Nutella Refill left
-        (0x19792, 0x1976E), // Nutella Refill right
-        (0x1920A, 0x191C2), // Kraid Recharge Station
-        (0x198A6, 0x19A7A), // Golden Torizo Energy Recharge
-        (0x1AA74, 0x1AA68), // Tourian Recharge Room
-        // Pants room interior door
-        (0x1A7A4, 0x1A78C), // Left door
-        (0x1A78C, 0x1A7A4), // Right door
-        // Items: (to avoid an interaction in map tiles between doors disappearing and items disappearing)
-        (0x18FA6, 0x18EDA), // First Missile Room
-        (0x18FFA, 0x18FEE), // Billy Mays Room
-        (0x18D66, 0x18D5A), // Brinstar Reserve Tank Room
-        (0x18F3A, 0x18F5E), // Etecoon Energy Tank Room (top left door)
-        (0x18F5E, 0x18F3A), // Etecoon Supers Room
-        (0x18E02, 0x18E62), // Big Pink (top door to Pink Brinstar Power Bomb Room)
-        (0x18FCA, 0x18FBE), // Hopper Energy Tank Room
-        (0x19132, 0x19126), // Spazer Room
-        (0x19162, 0x1914A), // Warehouse Energy Tank Room
-        (0x19252, 0x191DA), // Varia Suit Room
-        (0x18ADE, 0x18A36), // The Moat (left door)
-        (0x18C9A, 0x18C82), // The Final Missile
-        (0x18BE6, 0x18C3A), // Terminator Room (left door)
-        (0x18B0E, 0x18952), // Gauntlet Energy Tank Room (right door)
-        (0x1A924, 0x1A978), // Space Jump Room
-        (0x19A62, 0x198B2), // Ridley Tank Room
-        (0x199D2, 0x19A9E), // Lower Norfair Escape Power Bomb Room (left door)
-        (0x199DE, 0x199C6), // Lower Norfair Escape Power Bomb Room (top door)
-        (0x19876, 0x1983A), // Golden Torizo's Room (left door)
-        (0x19A86, 0x19882), // Screw Attack Room (left door)
-        (0x1941A, 0x192D6), // Hi Jump Energy Tank Room (right door)
-        (0x193F6, 0x19426), // Hi Jump Boots Room
-        (0x1929A, 0x19732), // Cathedral (right door)
-        (0x1953A, 0x19552), // Green Bubbles Missile Room
-        (0x195B2, 0x195BE), // Speed Booster Hall
-        (0x195BE, 0x195B2), // Speed Booster Room
-        (0x1962A, 0x1961E), // Wave Beam Room
-        (0x1935A, 0x1937E), // Ice Beam Room
-        (0x1938A, 0x19336), // Crumble Shaft (top right door)
-        (0x19402, 0x192E2), // Crocomire Escape (left door)
-        (0x1946E, 0x1943E), // Post Crocomire Power Bomb Room
-        (0x19516, 0x194DA), // Grapple Beam Room (bottom right door)
-        (0x1A2E8, 0x1A210), // Wrecked Ship West Super Room
-        (0x1A300, 0x18A06), // Gravity Suit Room (left door)
-        (0x1A30C, 0x1A1A4), // Gravity Suit Room (right door)
-    ]
-    .into_iter()
-    .map(|(x, y)| (Some(x), Some(y)))
-    .collect();
+    let mut non_randomizable_doors: HashSet<DoorPtrPair> = NON_RANDOMIZABLE_DOORS
+        .iter()
+        .filter(|(_, _, reason)| !difficulty.chaos_door_categories.contains(reason))
+        .map(|&(x, y, _)| (Some(x), Some(y)))
+        .collect();
 
-    // Avoid placing an ammo door on a tile with an objective "X", as it looks bad.
-    for i in difficulty.objectives.iter() {
-        use Objective::*;
-        match i {
-            SporeSpawn => {
-                non_randomizable_doors.insert((Some(0x18E4A), Some(0x18D2A)));
-            }
-            Crocomire => {
-                non_randomizable_doors.insert((Some(0x193DE), Some(0x19432)));
-            }
-            Botwoon => {
-                non_randomizable_doors.insert((Some(0x1A918), Some(0x1A84C)));
-            }
-            GoldenTorizo => {
-                non_randomizable_doors.insert((Some(0x19876), Some(0x1983A)));
-            }
-            MetroidRoom1 => {
-                non_randomizable_doors.insert((Some(0x1A9B4), Some(0x1A9C0))); // left
-                non_randomizable_doors.insert((Some(0x1A9A8), Some(0x1A984))); // right
-            }
-            MetroidRoom2 => {
-                non_randomizable_doors.insert((Some(0x1A9C0), Some(0x1A9B4))); // top right
-                non_randomizable_doors.insert((Some(0x1A9CC), Some(0x1A9D8))); // bottom right
-            }
-            MetroidRoom3 => {
-                non_randomizable_doors.insert((Some(0x1A9D8), Some(0x1A9CC))); // left
-                non_randomizable_doors.insert((Some(0x1A9E4), Some(0x1A9F0))); // right
-            }
-            MetroidRoom4 => {
-                non_randomizable_doors.insert((Some(0x1A9F0), Some(0x1A9E4))); // left
-                non_randomizable_doors.insert((Some(0x1A9FC), Some(0x1AA08))); // bottom
+    // Avoid placing an ammo door on a tile with an objective "X", as it looks bad -- unless the
+    // player has opted into chaos doors for item/objective tile conflicts.
+    if !difficulty
+        .chaos_door_categories
+        .contains(&DoorExclusionReason::ItemTileConflict)
+    {
+        for i in difficulty.objectives.iter() {
+            use Objective::*;
+            match i {
+                SporeSpawn => {
+                    non_randomizable_doors.insert((Some(0x18E4A), Some(0x18D2A)));
+                }
+                Crocomire => {
+                    non_randomizable_doors.insert((Some(0x193DE), Some(0x19432)));
+                }
+                Botwoon => {
+                    non_randomizable_doors.insert((Some(0x1A918), Some(0x1A84C)));
+                }
+                GoldenTorizo => {
+                    non_randomizable_doors.insert((Some(0x19876), Some(0x1983A)));
+                }
+                MetroidRoom1 => {
+                    non_randomizable_doors.insert((Some(0x1A9B4), Some(0x1A9C0))); // left
+                    non_randomizable_doors.insert((Some(0x1A9A8), Some(0x1A984))); // right
+                }
+                MetroidRoom2 => {
+                    non_randomizable_doors.insert((Some(0x1A9C0), Some(0x1A9B4))); // top right
+                    non_randomizable_doors.insert((Some(0x1A9CC), Some(0x1A9D8))); // bottom right
+                }
+                MetroidRoom3 => {
+                    non_randomizable_doors.insert((Some(0x1A9D8), Some(0x1A9CC))); // left
+                    non_randomizable_doors.insert((Some(0x1A9E4), Some(0x1A9F0))); // right
+                }
+                MetroidRoom4 => {
+                    non_randomizable_doors.insert((Some(0x1A9F0), Some(0x1A9E4))); // left
+                    non_randomizable_doors.insert((Some(0x1A9FC), Some(0x1AA08))); // bottom
+                }
+                _ => {} // All other tiles have gray doors and are excluded above.
             }
-            _ => {} // All other tiles have gray doors and are excluded above.
         }
     }
 
@@ -2452,6 +2763,199 @@ fn get_randomizable_door_connections(
     out
 }
 
+// Adjacency list over rooms (by `RoomGeometryRoomIdx`), used to check that locking a door doesn't
+// wall off part of the map from the ship start. Each edge also carries the door-pair connection
+// it corresponds to, so a specific connection can be excluded from the search.
+fn build_room_adjacency(
+    game_data: &GameData,
+    map: &Map,
+) -> HashMap<RoomGeometryRoomIdx, Vec<(RoomGeometryRoomIdx, (DoorPtrPair, DoorPtrPair))>> {
+    let mut adjacency: HashMap<RoomGeometryRoomIdx, Vec<(RoomGeometryRoomIdx, (DoorPtrPair, DoorPtrPair))>> =
+        HashMap::new();
+    for (src_ptr_pair, dst_ptr_pair, _bidirectional) in &map.doors {
+        let Some(&(src_room_idx, _)) = game_data.room_and_door_idxs_by_door_ptr_pair.get(src_ptr_pair) else {
+            continue;
+        };
+        let Some(&(dst_room_idx, _)) = game_data.room_and_door_idxs_by_door_ptr_pair.get(dst_ptr_pair) else {
+            continue;
+        };
+        adjacency
+            .entry(src_room_idx)
+            .or_default()
+            .push((dst_room_idx, (*src_ptr_pair, *dst_ptr_pair)));
+        adjacency
+            .entry(dst_room_idx)
+            .or_default()
+            .push((src_room_idx, (*dst_ptr_pair, *src_ptr_pair)));
+    }
+    adjacency
+}
+
+// Rooms reachable from `start`, pretending the player lacks whatever unlocks `excluded_conn` (i.e.
+// that connection's edges are removed from the graph). `None` excludes nothing, giving the full
+// baseline reachable set.
+fn rooms_reachable_excluding(
+    adjacency: &HashMap<RoomGeometryRoomIdx, Vec<(RoomGeometryRoomIdx, (DoorPtrPair, DoorPtrPair))>>,
+    start: RoomGeometryRoomIdx,
+    excluded_conn: Option<(DoorPtrPair, DoorPtrPair)>,
+) -> HashSet<RoomGeometryRoomIdx> {
+    let mut visited: HashSet<RoomGeometryRoomIdx> = HashSet::new();
+    let mut queue: Vec<RoomGeometryRoomIdx> = vec![start];
+    visited.insert(start);
+    while let Some(room_idx) = queue.pop() {
+        for &(next_idx, conn) in adjacency.get(&room_idx).map(Vec::as_slice).unwrap_or(&[]) {
+            if Some(conn) == excluded_conn || Some((conn.1, conn.0)) == excluded_conn {
+                continue;
+            }
+            if visited.insert(next_idx) {
+                queue.push(next_idx);
+            }
+        }
+    }
+    visited
+}
+
+// Would locking `conn` disconnect any room from the ship start room, assuming the player doesn't
+// yet have whatever unlocks it? This mirrors the connectivity-guarantee passes used by dungeon
+// generators: flood-fill with the candidate edge removed and check nothing becomes unreachable.
+fn would_disconnect_map(
+    adjacency: &HashMap<RoomGeometryRoomIdx, Vec<(RoomGeometryRoomIdx, (DoorPtrPair, DoorPtrPair))>>,
+    full_reachable: &HashSet<RoomGeometryRoomIdx>,
+    start_room_idx: RoomGeometryRoomIdx,
+    conn: (DoorPtrPair, DoorPtrPair),
+) -> bool {
+    let reachable = rooms_reachable_excluding(adjacency, start_room_idx, Some(conn));
+    reachable.len() < full_reachable.len()
+}
+
+// The ship's room (the start location under `StartLocationMode::Ship`) is room id
+// 8; find its `RoomGeometryRoomIdx` by cross-referencing the two door-ptr-pair maps that both key
+// off the same ptr pairs.
+fn find_ship_room_idx(game_data: &GameData) -> Option<RoomGeometryRoomIdx> {
+    const SHIP_ROOM_ID: RoomId = 8;
+    game_data
+        .door_ptr_pair_map
+        .iter()
+        .find(|(_, &(room_id, _))| room_id == SHIP_ROOM_ID)
+        .and_then(|(ptr_pair, _)| {
+            game_data
+                .room_and_door_idxs_by_door_ptr_pair
+                .get(ptr_pair)
+                .map(|&(room_idx, _)| room_idx)
+        })
+}
+
+// A door-type quota contributed by a `DoorPlacementBuilder`, with an optional dedup tag: types
+// sharing the same tag may not both be placed in the same room (e.g. "beam", for the existing
+// "at most one beam door per room" rule).
+#[derive(Clone, Copy)]
+pub struct DoorQuota {
+    pub door_type: DoorType,
+    pub room_group: Option<&'static str>,
+}
+
+pub struct DoorPlacementCtx<'a, R: Rng> {
+    pub difficulty: &'a DifficultyConfig,
+    pub rng: &'a mut R,
+    pub quotas: Vec<DoorQuota>,
+}
+
+impl<'a, R: Rng> DoorPlacementCtx<'a, R> {
+    pub fn push(&mut self, door_type: DoorType) {
+        self.quotas.push(DoorQuota {
+            door_type,
+            room_group: None,
+        });
+    }
+
+    pub fn push_grouped(&mut self, door_type: DoorType, room_group: &'static str) {
+        self.quotas.push(DoorQuota {
+            door_type,
+            room_group: Some(room_group),
+        });
+    }
+}
+
+// Contributes a quota of door types (and any per-room dedup grouping) to the general locked-door
+// pool. `randomize_doors` runs an ordered chain of these instead of a single hardcoded match on
+// `DoorsMode`, so a new door subsystem can be added by writing a new builder rather than editing
+// the central dispatch.
+pub trait DoorPlacementBuilder<R: Rng> {
+    fn contribute(&self, ctx: &mut DoorPlacementCtx<R>);
+}
+
+pub struct AmmoDoorBuilder;
+
+impl<R: Rng> DoorPlacementBuilder<R> for AmmoDoorBuilder {
+    fn contribute(&self, ctx: &mut DoorPlacementCtx<R>) {
+        let (red_cnt, green_cnt, yellow_cnt) = match ctx.difficulty.doors_mode {
+            DoorsMode::Blue => return,
+            DoorsMode::Ammo => (30, 15, 10),
+            DoorsMode::Beam => (18, 10, 7),
+        };
+        for _ in 0..red_cnt {
+            ctx.push(DoorType::Red);
+        }
+        for _ in 0..green_cnt {
+            ctx.push(DoorType::Green);
+        }
+        for _ in 0..yellow_cnt {
+            ctx.push(DoorType::Yellow);
+        }
+    }
+}
+
+pub struct BeamDoorBuilder;
+
+impl<R: Rng> DoorPlacementBuilder<R> for BeamDoorBuilder {
+    fn contribute(&self, ctx: &mut DoorPlacementCtx<R>) {
+        if ctx.difficulty.doors_mode != DoorsMode::Beam {
+            return;
+        }
+        let beam_door_each_cnt = 4;
+        for beam in [
+            BeamType::Charge,
+            BeamType::Ice,
+            BeamType::Wave,
+            BeamType::Spazer,
+            BeamType::Plasma,
+        ] {
+            for _ in 0..beam_door_each_cnt {
+                ctx.push_grouped(DoorType::Beam(beam), "beam");
+            }
+        }
+    }
+}
+
+// FEATURE-GATED OFF: see `DoorType::Objective`'s doc comment. There's no `Requirement` this crate
+// can AND into the traversal to enforce these doors, so rather than place doors the solver can't
+// actually account for, this builder never contributes any and just warns if it was configured to.
+pub struct ObjectiveDoorBuilder;
+
+impl<R: Rng> DoorPlacementBuilder<R> for ObjectiveDoorBuilder {
+    fn contribute(&self, ctx: &mut DoorPlacementCtx<R>) {
+        if ctx.difficulty.objective_locked_door_count > 0 {
+            log::warn!(
+                "objective_locked_door_count is {} but DoorType::Objective placement is \
+                 feature-gated off (unenforceable by the solver); no objective-locked doors will \
+                 be placed",
+                ctx.difficulty.objective_locked_door_count
+            );
+        }
+    }
+}
+
+// The builder chain used by `randomize_doors`. `randomize_doors` always runs exactly this chain:
+// it has no parameter for a caller-supplied chain, so builders outside this list are not run by it.
+pub fn default_door_placement_builders<R: Rng + 'static>() -> Vec<Box<dyn DoorPlacementBuilder<R>>>
+{
+    vec![
+        Box::new(AmmoDoorBuilder),
+        Box::new(BeamDoorBuilder),
+        Box::new(ObjectiveDoorBuilder),
+    ]
+}
+
 pub fn randomize_doors(
     game_data: &GameData,
     map: &Map,
@@ -2469,68 +2973,129 @@ pub fn randomize_doors(
         (room_idx, door.x, door.y)
     };
     let mut used_locs: HashSet<(RoomGeometryRoomIdx, usize, usize)> = HashSet::new();
-    let mut used_beam_rooms: HashSet<RoomGeometryRoomIdx> = HashSet::new();
-    let mut door_types = vec![];
-
-    match difficulty.doors_mode {
-        DoorsMode::Blue => {}
-        DoorsMode::Ammo => {
-            let red_doors_cnt = 30;
-            let green_doors_cnt = 15;
-            let yellow_doors_cnt = 10;
-            door_types.extend(vec![DoorType::Red; red_doors_cnt]);
-            door_types.extend(vec![DoorType::Green; green_doors_cnt]);
-            door_types.extend(vec![DoorType::Yellow; yellow_doors_cnt]);
-        }
-        DoorsMode::Beam => {
-            let red_doors_cnt = 18;
-            let green_doors_cnt = 10;
-            let yellow_doors_cnt = 7;
-            let beam_door_each_cnt = 4;
-            door_types.extend(vec![DoorType::Red; red_doors_cnt]);
-            door_types.extend(vec![DoorType::Green; green_doors_cnt]);
-            door_types.extend(vec![DoorType::Yellow; yellow_doors_cnt]);
-            door_types.extend(vec![DoorType::Beam(BeamType::Charge); beam_door_each_cnt]);
-            door_types.extend(vec![DoorType::Beam(BeamType::Ice); beam_door_each_cnt]);
-            door_types.extend(vec![DoorType::Beam(BeamType::Wave); beam_door_each_cnt]);
-            door_types.extend(vec![DoorType::Beam(BeamType::Spazer); beam_door_each_cnt]);
-            door_types.extend(vec![DoorType::Beam(BeamType::Plasma); beam_door_each_cnt]);
-        }
+    let mut used_room_groups: HashMap<&'static str, HashSet<RoomGeometryRoomIdx>> = HashMap::new();
+
+    let mut ctx = DoorPlacementCtx {
+        difficulty,
+        rng: &mut rng,
+        quotas: vec![],
     };
+    for builder in default_door_placement_builders() {
+        builder.contribute(&mut ctx);
+    }
+    let quotas = ctx.quotas;
+    drop(ctx);
+
     let door_conns = get_randomizable_door_connections(game_data, map, difficulty);
     let mut locked_doors: Vec<LockedDoor> = vec![];
-    let total_cnt = door_types.len();
-    let idxs = rand::seq::index::sample(&mut rng, door_conns.len(), total_cnt);
-    for (i, idx) in idxs.into_iter().enumerate() {
+    let total_cnt = quotas.len();
+
+    // Reachability guard: a locked door must never be the only way to reach some part of the
+    // map, since without the item/objective that unlocks it the player would be stuck. We check
+    // this by flood-filling from the ship with the candidate connection's edges removed and
+    // comparing against the full (all-doors-open) reachable set.
+    let room_adjacency = build_room_adjacency(game_data, map);
+    let ship_room_idx = find_ship_room_idx(game_data);
+    let full_reachable =
+        ship_room_idx.map(|start| rooms_reachable_excluding(&room_adjacency, start, None));
+
+    // Oversample candidate connections so that, after rejecting ones that would self-block or
+    // collide on a tile/room, we still have enough left to place every door type. Connections
+    // that end up unused are simply left as ordinary (unlocked) doors.
+    let sample_cnt = usize::min(door_conns.len(), total_cnt * 4 + 16);
+    let idxs = rand::seq::index::sample(&mut rng, door_conns.len(), sample_cnt);
+    let mut pending_quotas: VecDeque<DoorQuota> = quotas.into_iter().collect();
+    for idx in idxs.into_iter() {
+        let Some(quota) = pending_quotas.pop_front() else {
+            break;
+        };
         let conn = &door_conns[idx];
         let door = LockedDoor {
             src_ptr_pair: conn.0,
             dst_ptr_pair: conn.1,
-            door_type: door_types[i],
+            door_type: quota.door_type,
             bidirectional: true,
         };
 
+        if let (Some(start), Some(full_reachable)) = (ship_room_idx, full_reachable.as_ref()) {
+            if would_disconnect_map(
+                &room_adjacency,
+                full_reachable,
+                start,
+                (door.src_ptr_pair, door.dst_ptr_pair),
+            ) {
+                // This connection is the only way into part of the map; leave it unlocked and
+                // try this door type again on a different connection.
+                pending_quotas.push_back(quota);
+                continue;
+            }
+        }
+
         // Make sure we don't put two ammo doors in the same tile (since that would interfere
         // with the mechanism for making the doors disappear from the map).
         let src_loc = get_loc(door.src_ptr_pair);
         let dst_loc = get_loc(door.dst_ptr_pair);
         if used_locs.contains(&src_loc) || used_locs.contains(&dst_loc) {
+            pending_quotas.push_back(quota);
             continue;
         }
-        if let DoorType::Beam(_) = door_types[i] {
+        if let Some(room_group) = quota.room_group {
             let src_room_idx = src_loc.0;
             let dst_room_idx = dst_loc.0;
-            if used_beam_rooms.contains(&src_room_idx) || used_beam_rooms.contains(&dst_room_idx) {
+            let used_rooms = used_room_groups.entry(room_group).or_default();
+            if used_rooms.contains(&src_room_idx) || used_rooms.contains(&dst_room_idx) {
+                pending_quotas.push_back(quota);
                 continue;
             }
-            used_beam_rooms.insert(src_room_idx);
-            used_beam_rooms.insert(dst_room_idx);
+            used_rooms.insert(src_room_idx);
+            used_rooms.insert(dst_room_idx);
         }
         used_locs.insert(src_loc);
         used_locs.insert(dst_loc);
         locked_doors.push(door);
     }
 
+    // FEATURE-GATED OFF: see `DoorType::CombatLock`'s doc comment. There's no per-room enemy-clear
+    // requirement this crate can AND into the entering `get_come_in_*` requirement, so rather than
+    // place doors the solver can't actually account for, combat-lock doors are never placed; we
+    // just warn if the setting was configured to ask for them.
+    if difficulty.combat_lock_door_count > 0 {
+        log::warn!(
+            "combat_lock_door_count is {} but DoorType::CombatLock placement is feature-gated off \
+             (unenforceable by the solver); no combat-lock doors will be placed",
+            difficulty.combat_lock_door_count
+        );
+    }
+
+    // Map-revealer hatches are purely cosmetic, so (unlike the other door types above) they never
+    // need the reachability guard: locking nothing, they can't cut the player off from anything.
+    if difficulty.map_revealer_door_count > 0 {
+        let revealer_sample_cnt = usize::min(door_conns.len(), difficulty.map_revealer_door_count * 4 + 16);
+        let revealer_idxs = rand::seq::index::sample(&mut rng, door_conns.len(), revealer_sample_cnt);
+        let mut remaining = difficulty.map_revealer_door_count;
+        for idx in revealer_idxs.into_iter() {
+            if remaining == 0 {
+                break;
+            }
+            let conn = &door_conns[idx];
+            let door = LockedDoor {
+                src_ptr_pair: conn.0,
+                dst_ptr_pair: conn.1,
+                door_type: DoorType::MapRevealer,
+                bidirectional: true,
+            };
+            let src_loc = get_loc(door.src_ptr_pair);
+            let dst_loc = get_loc(door.dst_ptr_pair);
+            if used_locs.contains(&src_loc) || used_locs.contains(&dst_loc) {
+                continue;
+            }
+            used_locs.insert(src_loc);
+            used_locs.insert(dst_loc);
+            locked_doors.push(door);
+            remaining -= 1;
+        }
+    }
+
     let mut locked_door_node_map: HashMap<(RoomId, NodeId), usize> = HashMap::new();
     for (i, door) in locked_doors.iter().enumerate() {
         let (src_room_id, src_node_id) = game_data.door_ptr_pair_map[&door.src_ptr_pair];
@@ -2600,6 +3165,109 @@ pub fn filter_links(
     out
 }
 
+// Folds a `Requirement` tree bottom-up, replacing `Tech`/`Strat` leaves with `Free`/`Never`
+// according to the seed's settings and then collapsing the resulting `And`/`Or` nodes. Only
+// leaves that are constant for the whole seed are touched here; resource-dependent leaves (energy,
+// ammo counts, etc.) fall through the wildcard arm unchanged, since folding those would require
+// knowing the player's state at traversal time rather than just the settings.
+fn simplify_requirement(
+    req: &Requirement,
+    tech_active: &[bool],
+    strats_active: &[bool],
+) -> Requirement {
+    match req {
+        Requirement::Tech(tech_id) => {
+            if tech_active[*tech_id] {
+                Requirement::Free
+            } else {
+                Requirement::Never
+            }
+        }
+        Requirement::Strat(strat_id) => {
+            if strats_active[*strat_id] {
+                Requirement::Free
+            } else {
+                Requirement::Never
+            }
+        }
+        Requirement::And(reqs) => {
+            let mut out: Vec<Requirement> = vec![];
+            let mut seen: HashSet<String> = HashSet::new();
+            for r in reqs {
+                match simplify_requirement(r, tech_active, strats_active) {
+                    Requirement::Free => {}
+                    Requirement::Never => return Requirement::Never,
+                    Requirement::And(inner) => {
+                        for r in inner {
+                            if seen.insert(format!("{:?}", r)) {
+                                out.push(r);
+                            }
+                        }
+                    }
+                    other => {
+                        if seen.insert(format!("{:?}", other)) {
+                            out.push(other);
+                        }
+                    }
+                }
+            }
+            match out.len() {
+                0 => Requirement::Free,
+                1 => out.into_iter().next().unwrap(),
+                _ => Requirement::And(out),
+            }
+        }
+        Requirement::Or(reqs) => {
+            let mut out: Vec<Requirement> = vec![];
+            let mut seen: HashSet<String> = HashSet::new();
+            for r in reqs {
+                match simplify_requirement(r, tech_active, strats_active) {
+                    Requirement::Never => {}
+                    Requirement::Free => return Requirement::Free,
+                    Requirement::Or(inner) => {
+                        for r in inner {
+                            if seen.insert(format!("{:?}", r)) {
+                                out.push(r);
+                            }
+                        }
+                    }
+                    other => {
+                        if seen.insert(format!("{:?}", other)) {
+                            out.push(other);
+                        }
+                    }
+                }
+            }
+            match out.len() {
+                0 => Requirement::Never,
+                1 => out.into_iter().next().unwrap(),
+                _ => Requirement::Or(out),
+            }
+        }
+        other => other.clone(),
+    }
+}
+
+// Applies `simplify_requirement` to every link's requirement tree once per seed, so the hot
+// `traverse` loop re-evaluates a minimized tree on every step instead of re-walking fully intact
+// `And`/`Or` trees. Settings that change `tech_active`/`strats_active` (e.g. a preset switch)
+// require re-running this, same as `filter_links`.
+pub fn simplify_links(
+    links: &[Link],
+    game_data: &GameData,
+    difficulty: &DifficultyConfig,
+) -> Vec<Link> {
+    let tech_vec = get_tech_vec(game_data, difficulty);
+    let strat_vec = get_strat_vec(game_data, difficulty);
+    links
+        .iter()
+        .map(|link| Link {
+            requirement: simplify_requirement(&link.requirement, &tech_vec, &strat_vec),
+            ..link.clone()
+        })
+        .collect()
+}
+
 fn get_tech_vec(game_data: &GameData, difficulty: &DifficultyConfig) -> Vec<bool> {
     let tech_set: HashSet<String> = difficulty.tech.iter().map(|x| x.clone()).collect();
     game_data
@@ -2671,6 +3339,13 @@ pub fn strip_name(s: &str) -> String {
     out
 }
 
+// Outcome of a single beam-search candidate's step, used by `randomize_beam`/`classify_beam_step`.
+enum BeamStepOutcome {
+    Continue,
+    Succeeded,
+    Failed,
+}
+
 impl<'r> Randomizer<'r> {
     pub fn new(
         map: &'r Map,
@@ -2680,7 +3355,44 @@ impl<'r> Randomizer<'r> {
         base_links_data: &'r LinksDataGroup,
     ) -> Randomizer<'r> {
         let preprocessor = Preprocessor::new(game_data, map, &difficulty_tiers[0]);
-        let preprocessed_seed_links: Vec<Link> = preprocessor.get_all_door_links();
+        let toilet_intersections = Self::get_toilet_intersections(map, game_data);
+        let door_link_cache_dir = Path::new("cache/door_links");
+        let door_map_entries: Vec<((usize, usize), (usize, usize))> =
+            preprocessor.door_map.iter().map(|(&k, &v)| (k, v)).collect();
+        let door_link_cache_key = door_link_cache::compute_cache_key(
+            &door_map_entries,
+            &toilet_intersections,
+            &difficulty_tiers[0],
+        );
+        let preprocessed_seed_links: Vec<Link> =
+            match door_link_cache::load(door_link_cache_dir, &door_link_cache_key) {
+                Some(links) => {
+                    info!("Loaded preprocessed door links from cache");
+                    links
+                }
+                None => {
+                    let links = preprocessor.get_all_door_links();
+                    if let Err(err) =
+                        door_link_cache::store(door_link_cache_dir, &door_link_cache_key, &links)
+                    {
+                        info!("Failed to write door link cache: {}", err);
+                    }
+                    links
+                }
+            };
+        let seed_links_data_tiers: Vec<LinksDataGroup> = difficulty_tiers
+            .iter()
+            .map(|difficulty| {
+                let simplified = simplify_links(&preprocessed_seed_links, game_data, difficulty);
+                LinksDataGroup::new(
+                    simplified,
+                    game_data.vertex_isv.keys.len(),
+                    base_links_data.links.len(),
+                )
+            })
+            .collect();
+        let preprocessed_seed_links =
+            simplify_links(&preprocessed_seed_links, game_data, &difficulty_tiers[0]);
         info!(
             "{} base links, {} door links",
             base_links_data.links.len(),
@@ -2722,8 +3434,6 @@ impl<'r> Randomizer<'r> {
         initial_items_remaining[Item::Nothing as usize] =
             game_data.item_locations.len() - initial_items_remaining.iter().sum::<usize>();
 
-        let toilet_intersections = Self::get_toilet_intersections(map, game_data);
-
         Randomizer {
             map,
             toilet_intersections,
@@ -2736,6 +3446,7 @@ impl<'r> Randomizer<'r> {
                 game_data.vertex_isv.keys.len(),
                 base_links_data.links.len(),
             ),
+            seed_links_data_tiers,
             difficulty_tiers,
         }
     }
@@ -2790,6 +3501,24 @@ impl<'r> Randomizer<'r> {
         flag_vec
     }
 
+    // Item/flag placement only ever adds to `GlobalState` (items and flags are never taken away
+    // mid-generation), so reachability is monotone: any vertex already marked reachable in the
+    // previous step's `TraverseResult` is still reachable now. We exploit this by seeding `traverse`
+    // with the prior step's result (when the hub location hasn't changed) instead of starting from
+    // scratch, so the fixpoint only has to propagate newly-unlocked edges rather than re-deriving
+    // the whole graph every step.
+    fn get_incremental_init(&self, state: &RandomizationState, reverse: bool) -> Option<TraverseResult> {
+        // The hub location is fixed for the whole `randomize` attempt (it is only set once, in
+        // `determine_start_location`, and simply carried forward from step to step), so a previous
+        // result is always rooted at the same start vertex we're about to traverse from again here.
+        let previous = state.previous_debug_data.as_ref()?;
+        if reverse {
+            Some(previous.reverse.clone())
+        } else {
+            Some(previous.forward.clone())
+        }
+    }
+
     fn update_reachability(&self, state: &mut RandomizationState) {
         let num_vertices = self.game_data.vertex_isv.keys.len();
         let start_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
@@ -2798,31 +3527,41 @@ impl<'r> Randomizer<'r> {
             obstacle_mask: 0,
             actions: vec![],
         }];
-        let mut forward = traverse(
-            &self.base_links_data,
-            &self.seed_links_data,
-            None,
-            &state.global_state,
-            LocalState::new(),
-            num_vertices,
-            start_vertex_id,
-            false,
-            &self.difficulty_tiers[0],
-            self.game_data,
-            &self.locked_door_data,
-        );
-        let mut reverse = traverse(
-            &self.base_links_data,
-            &self.seed_links_data,
-            None,
-            &state.global_state,
-            LocalState::new(),
-            num_vertices,
-            start_vertex_id,
-            true,
-            &self.difficulty_tiers[0],
-            self.game_data,
-            &self.locked_door_data,
+        let forward_init = self.get_incremental_init(state, false);
+        let reverse_init = self.get_incremental_init(state, true);
+        // The forward and reverse traversals are independent fixpoints over the same link graph,
+        // so we run them concurrently rather than back-to-back.
+        let (mut forward, mut reverse) = rayon::join(
+            || {
+                traverse(
+                    &self.base_links_data,
+                    &self.seed_links_data,
+                    forward_init,
+                    &state.global_state,
+                    LocalState::new(),
+                    num_vertices,
+                    start_vertex_id,
+                    false,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    &self.locked_door_data,
+                )
+            },
+            || {
+                traverse(
+                    &self.base_links_data,
+                    &self.seed_links_data,
+                    reverse_init,
+                    &state.global_state,
+                    LocalState::new(),
+                    num_vertices,
+                    start_vertex_id,
+                    true,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    &self.locked_door_data,
+                )
+            },
         );
         for (i, vertex_ids) in self.game_data.item_vertex_ids.iter().enumerate() {
             // Clear out any previous bireachable markers (because in rare cases a previously bireachable
@@ -2840,6 +3579,10 @@ impl<'r> Randomizer<'r> {
                     {
                         state.item_location_state[i].bireachable = true;
                         state.item_location_state[i].bireachable_vertex_id = Some(v);
+                        if state.item_location_state[i].first_bireachable_step.is_none() {
+                            state.item_location_state[i].first_bireachable_step =
+                                Some(state.step_num);
+                        }
                     }
                 }
             }
@@ -2858,6 +3601,10 @@ impl<'r> Randomizer<'r> {
                     if !state.flag_location_state[i].reachable {
                         state.flag_location_state[i].reachable = true;
                         state.flag_location_state[i].reachable_vertex_id = Some(v);
+                        if state.flag_location_state[i].first_reachable_step.is_none() {
+                            state.flag_location_state[i].first_reachable_step =
+                                Some(state.step_num);
+                        }
                     }
                     if !state.flag_location_state[i].bireachable
                         && get_bireachable_idxs(&state.global_state, v, &mut forward, &mut reverse)
@@ -2865,6 +3612,10 @@ impl<'r> Randomizer<'r> {
                     {
                         state.flag_location_state[i].bireachable = true;
                         state.flag_location_state[i].bireachable_vertex_id = Some(v);
+                        if state.flag_location_state[i].first_bireachable_step.is_none() {
+                            state.flag_location_state[i].first_bireachable_step =
+                                Some(state.step_num);
+                        }
                     }
                 }
             }
@@ -2889,6 +3640,9 @@ impl<'r> Randomizer<'r> {
                     {
                         state.door_state[i].bireachable = true;
                         state.door_state[i].bireachable_vertex_id = Some(v);
+                        if state.door_state[i].first_bireachable_step.is_none() {
+                            state.door_state[i].first_bireachable_step = Some(state.step_num);
+                        }
                     }
                 }
             }
@@ -2916,34 +3670,414 @@ impl<'r> Randomizer<'r> {
         });
     }
 
-    // Determine how many key items vs. filler items to place on this step.
-    fn determine_item_split(
+    // For each item location, finds the minimum set of door-unlock "keys" (ammo/beam items that
+    // gate at least one locked door in this seed) needed to bireachably collect it, along with an
+    // abstract cost (the size of that key set). This is a Dijkstra over the product state space of
+    // (graph vertex, key-bitset acquired): since there are only `relevant_keys.len()` distinct
+    // keys, the bitset side of the product is contracted down to at most 2^k states, each of which
+    // is resolved against the full vertex graph by one forward+reverse traversal (reusing the same
+    // `traverse`/`get_bireachable_idxs` logic as `update_reachability`, so results stay
+    // logic-accurate). Items that remain unreachable even with every key come back as `None`.
+    fn compute_key_unlock_analysis(
         &self,
         state: &RandomizationState,
-        num_bireachable: usize,
-        num_oneway_reachable: usize,
-    ) -> (usize, usize) {
-        let num_items_to_place = num_bireachable + num_oneway_reachable;
-        let filtered_item_precedence: Vec<Item> = state
-            .item_precedence
+    ) -> Vec<Option<(Vec<Item>, usize)>> {
+        let relevant_keys: Vec<Item> = DOOR_KEY_ITEMS
             .iter()
             .copied()
-            .filter(|&item| {
-                state.items_remaining[item as usize] == self.initial_items_remaining[item as usize]
+            .filter(|&key| {
+                self.locked_door_data
+                    .locked_doors
+                    .iter()
+                    .any(|d| door_key_item(d.door_type) == Some(key))
             })
             .collect();
-        let num_key_items_remaining = filtered_item_precedence.len();
-        let num_items_remaining: usize = state.items_remaining.iter().sum();
-        let mut num_key_items_to_place = match self.difficulty_tiers[0].progression_rate {
-            ProgressionRate::Slow => 1,
-            ProgressionRate::Uniform => usize::max(
-                1,
-                f32::round(
-                    (num_key_items_remaining as f32) / (num_items_remaining as f32)
-                        * (num_items_to_place as f32),
-                ) as usize,
-            ),
-            ProgressionRate::Fast => usize::max(
+
+        let num_vertices = self.game_data.vertex_isv.keys.len();
+        let start_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
+            room_id: state.hub_location.room_id,
+            node_id: state.hub_location.node_id,
+            obstacle_mask: 0,
+            actions: vec![],
+        }];
+
+        let num_keys = relevant_keys.len();
+        let mut best_cost: Vec<Option<usize>> = vec![None; 1 << num_keys];
+        let mut heap: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+        best_cost[0] = Some(0);
+        heap.push(Reverse((0, 0)));
+
+        let mut result: Vec<Option<(Vec<Item>, usize)>> =
+            vec![None; state.item_location_state.len()];
+        let mut remaining = result.len();
+
+        while let Some(Reverse((cost, mask))) = heap.pop() {
+            if remaining == 0 {
+                break;
+            }
+            if best_cost[mask] != Some(cost) {
+                continue; // stale entry superseded by a cheaper route to this key-set
+            }
+
+            let mut trial_global_state = state.global_state.clone();
+            for (i, &key) in relevant_keys.iter().enumerate() {
+                trial_global_state.items[key as usize] = mask & (1 << i) != 0;
+            }
+
+            let (mut forward, mut reverse) = rayon::join(
+                || {
+                    traverse(
+                        &self.base_links_data,
+                        &self.seed_links_data,
+                        None,
+                        &trial_global_state,
+                        LocalState::new(),
+                        num_vertices,
+                        start_vertex_id,
+                        false,
+                        &self.difficulty_tiers[0],
+                        self.game_data,
+                        &self.locked_door_data,
+                    )
+                },
+                || {
+                    traverse(
+                        &self.base_links_data,
+                        &self.seed_links_data,
+                        None,
+                        &trial_global_state,
+                        LocalState::new(),
+                        num_vertices,
+                        start_vertex_id,
+                        true,
+                        &self.difficulty_tiers[0],
+                        self.game_data,
+                        &self.locked_door_data,
+                    )
+                },
+            );
+
+            for (i, vertex_ids) in self.game_data.item_vertex_ids.iter().enumerate() {
+                if result[i].is_some() {
+                    continue;
+                }
+                for &v in vertex_ids {
+                    if forward.cost[v].iter().any(|&x| f32::is_finite(x))
+                        && get_bireachable_idxs(&trial_global_state, v, &mut forward, &mut reverse)
+                            .is_some()
+                    {
+                        let keys: Vec<Item> = relevant_keys
+                            .iter()
+                            .enumerate()
+                            .filter(|&(j, _)| mask & (1 << j) != 0)
+                            .map(|(_, &key)| key)
+                            .collect();
+                        result[i] = Some((keys, cost));
+                        remaining -= 1;
+                        break;
+                    }
+                }
+            }
+
+            for i in 0..num_keys {
+                let bit = 1 << i;
+                if mask & bit != 0 {
+                    continue;
+                }
+                let next_mask = mask | bit;
+                let next_cost = cost + 1;
+                if best_cost[next_mask].map_or(true, |c| next_cost < c) {
+                    best_cost[next_mask] = Some(next_cost);
+                    heap.push(Reverse((next_cost, next_mask)));
+                }
+            }
+        }
+
+        result
+    }
+
+    // Every distinct item actually placed in this seed that isn't classified as filler by
+    // `DifficultyConfig`. This is the "key item" universe for `compute_critical_path_analysis`,
+    // mirroring the key-vs-filler split that `determine_item_split`/`select_filler_items` already
+    // use for placement.
+    fn key_item_universe(&self, state: &RandomizationState) -> Vec<Item> {
+        let difficulty = &self.difficulty_tiers[0];
+        let mut items: Vec<Item> = vec![];
+        for loc in &state.item_location_state {
+            let Some(item) = loc.placed_item else {
+                continue;
+            };
+            if difficulty.filler_items.contains(&item)
+                || difficulty.semi_filler_items.contains(&item)
+                || difficulty.early_filler_items.contains(&item)
+            {
+                continue;
+            }
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+        items
+    }
+
+    // Finds a minimum-cardinality set of key items (see `key_item_universe`) that makes Mother
+    // Brain reachable, via a Dijkstra over the *set of collected key items* rather than graph
+    // position: the search state is a bitmask of which key items have been "collected", deduped by
+    // mask, and each state is resolved against the full vertex graph by one forward traversal
+    // (reusing `traverse`, so results stay logic-accurate, as in `compute_key_unlock_analysis`).
+    // Only one-way reachability is required, matching `is_game_beatable`. Returns the items of a
+    // discovered minimal set in the order the search added them to the mask (which is not
+    // necessarily an order in which they can be collected), or `None` if Mother Brain isn't
+    // reachable with every key item in hand (shouldn't happen in a completable seed) or there are
+    // more than 12 key items.
+    //
+    // Unlike `compute_key_unlock_analysis`, whose bitset is bounded by the small fixed
+    // `DOOR_KEY_ITEMS` list regardless of seed settings, `key_item_universe` here is every
+    // non-filler item actually placed, which on an ordinary (non-filler-heavy) config commonly
+    // runs 15-20+. A Dijkstra that (in the worst case, when most keys are required) has to expand
+    // most of the `2^num_keys` subsets before finding one that reaches Mother Brain is already
+    // impractical well under that, so the search gives up above 12 key items (at most 2^12
+    // subsets) rather than near the hard array-index limit.
+    fn compute_critical_path_analysis(&self, state: &RandomizationState) -> Option<Vec<Item>> {
+        let key_items = self.key_item_universe(state);
+        let num_keys = key_items.len();
+        if num_keys > 12 {
+            return None;
+        }
+
+        let num_vertices = self.game_data.vertex_isv.keys.len();
+        let start_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
+            room_id: state.hub_location.room_id,
+            node_id: state.hub_location.node_id,
+            obstacle_mask: 0,
+            actions: vec![],
+        }];
+        let mother_brain_flag_idx = self
+            .game_data
+            .flag_ids
+            .iter()
+            .position(|&f| f == self.game_data.mother_brain_defeated_flag_id)?;
+        let goal_vertex_ids = &self.game_data.flag_vertex_ids[mother_brain_flag_idx];
+
+        let mut best_cost: Vec<Option<usize>> = vec![None; 1 << num_keys];
+        let mut came_from: Vec<Option<(usize, usize)>> = vec![None; 1 << num_keys];
+        let mut heap: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+        best_cost[0] = Some(0);
+        heap.push(Reverse((0, 0)));
+
+        let mut winning_mask: Option<usize> = None;
+        while let Some(Reverse((cost, mask))) = heap.pop() {
+            if best_cost[mask] != Some(cost) {
+                continue; // stale entry superseded by a cheaper route to this key-set
+            }
+
+            let mut trial_global_state = state.global_state.clone();
+            for (i, &key) in key_items.iter().enumerate() {
+                trial_global_state.items[key as usize] = mask & (1 << i) != 0;
+            }
+
+            let forward = traverse(
+                &self.base_links_data,
+                &self.seed_links_data,
+                None,
+                &trial_global_state,
+                LocalState::new(),
+                num_vertices,
+                start_vertex_id,
+                false,
+                &self.difficulty_tiers[0],
+                self.game_data,
+                &self.locked_door_data,
+            );
+
+            if goal_vertex_ids
+                .iter()
+                .any(|&v| forward.cost[v].iter().any(|&x| f32::is_finite(x)))
+            {
+                winning_mask = Some(mask);
+                break;
+            }
+
+            for i in 0..num_keys {
+                let bit = 1 << i;
+                if mask & bit != 0 {
+                    continue;
+                }
+                let next_mask = mask | bit;
+                let next_cost = cost + 1;
+                if best_cost[next_mask].map_or(true, |c| next_cost < c) {
+                    best_cost[next_mask] = Some(next_cost);
+                    came_from[next_mask] = Some((mask, i));
+                    heap.push(Reverse((next_cost, next_mask)));
+                }
+            }
+        }
+
+        let mut mask = winning_mask?;
+        let mut order: Vec<Item> = vec![];
+        while mask != 0 {
+            let (prev_mask, key_idx) = came_from[mask].unwrap();
+            order.push(key_items[key_idx]);
+            mask = prev_mask;
+        }
+        order.reverse();
+        Some(order)
+    }
+
+    // Builds a directed prerequisite graph over items, flags, and locked doors: for each
+    // objective, the set of already-acquired objectives at the first step it became bireachable
+    // (not when collected) becomes its incoming edges. Walked in step order using the
+    // reachability frontier `get_spoiler_summary` already records in `SpoilerSummary::reachable`,
+    // so each objective's prerequisite set is a snapshot of everything acquired on strictly
+    // earlier steps, which keeps the graph acyclic by construction.
+    fn compute_dependency_graph(&self, spoiler_summaries: &[SpoilerSummary]) -> SpoilerDependencyGraph {
+        let mut nodes: Vec<SpoilerDependencyNode> = Vec::new();
+        let mut acquired: Vec<String> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+
+        for summary in spoiler_summaries {
+            let prerequisites = acquired.clone();
+            for item in &summary.reachable.items {
+                let id = format!("item:{}/{}/{}", item.location.room, item.location.node, item.item);
+                if seen.insert(id.clone()) {
+                    nodes.push(SpoilerDependencyNode {
+                        id,
+                        kind: "item".to_string(),
+                        name: item.item.clone(),
+                        step: summary.step,
+                        prerequisites: prerequisites.clone(),
+                    });
+                }
+            }
+            for flag in &summary.reachable.flags {
+                let id = format!("flag:{}", flag.flag);
+                if seen.insert(id.clone()) {
+                    nodes.push(SpoilerDependencyNode {
+                        id,
+                        kind: "flag".to_string(),
+                        name: flag.flag.clone(),
+                        step: summary.step,
+                        prerequisites: prerequisites.clone(),
+                    });
+                }
+            }
+            for door in &summary.reachable.doors {
+                let id = format!(
+                    "door:{}/{}/{}",
+                    door.location.room, door.location.node, door.door_type
+                );
+                if seen.insert(id.clone()) {
+                    nodes.push(SpoilerDependencyNode {
+                        id,
+                        kind: "door".to_string(),
+                        name: door.door_type.clone(),
+                        step: summary.step,
+                        prerequisites: prerequisites.clone(),
+                    });
+                }
+            }
+
+            // Objectives actually collected/unlocked this step become prerequisites for later
+            // steps (acquiring them all at once is fine: they don't depend on one another).
+            for item in &summary.items {
+                acquired.push(format!(
+                    "item:{}/{}/{}",
+                    item.location.room, item.location.node, item.item
+                ));
+            }
+            for flag in &summary.flags {
+                acquired.push(format!("flag:{}", flag.flag));
+            }
+            for door in &summary.doors {
+                acquired.push(format!(
+                    "door:{}/{}/{}",
+                    door.location.room, door.location.node, door.door_type
+                ));
+            }
+        }
+
+        SpoilerDependencyGraph { nodes }
+    }
+
+    // Builds `SpoilerRouteMetrics` by walking every `SpoilerDetails` step in playthrough order and
+    // tallying, over each item/flag/door's obtain and return routes, the per-hop increase in
+    // resources spent (energy + reserves + missiles + supers + power bombs).
+    fn compute_route_metrics(&self, spoiler_details: &[SpoilerDetails]) -> SpoilerRouteMetrics {
+        let mut total_steps: usize = 0;
+        let mut area_counts: Vec<(String, usize)> = Vec::new();
+        let mut tally_route = |route: &[SpoilerRouteEntry]| {
+            // `energy_used`/etc. on each entry are cumulative over the route, so the cost of an
+            // individual hop is the increase over the entry before it (0 for the first hop).
+            let mut prev_cost: Capacity = 0;
+            for entry in route {
+                let cumulative_cost = entry.energy_used.unwrap_or(0)
+                    + entry.reserves_used.unwrap_or(0)
+                    + entry.missiles_used.unwrap_or(0)
+                    + entry.supers_used.unwrap_or(0)
+                    + entry.power_bombs_used.unwrap_or(0);
+                // A decrease (e.g. a refill between waypoints) isn't a cost to travel this hop, so
+                // clamp to the non-negative direction instead of taking the absolute value, which
+                // would otherwise count a refill as if it were extra distance.
+                let hop_cost = (cumulative_cost - prev_cost).max(0) as usize;
+                prev_cost = cumulative_cost;
+                total_steps += hop_cost;
+                match area_counts.iter_mut().find(|(area, _)| *area == entry.area) {
+                    Some((_, count)) => *count += hop_cost,
+                    None => area_counts.push((entry.area.clone(), hop_cost)),
+                }
+            }
+        };
+        for details in spoiler_details {
+            for item in &details.items {
+                tally_route(&item.obtain_route);
+                tally_route(&item.return_route);
+            }
+            for flag in &details.flags {
+                tally_route(&flag.obtain_route);
+                tally_route(&flag.return_route);
+            }
+            for door in &details.doors {
+                tally_route(&door.obtain_route);
+                tally_route(&door.return_route);
+            }
+        }
+        SpoilerRouteMetrics {
+            total_steps,
+            area_breakdown: area_counts
+                .into_iter()
+                .map(|(area, steps)| SpoilerAreaSteps { area, steps })
+                .collect(),
+        }
+    }
+
+    // Determine how many key items vs. filler items to place on this step.
+    fn determine_item_split(
+        &self,
+        state: &RandomizationState,
+        num_bireachable: usize,
+        num_oneway_reachable: usize,
+    ) -> (usize, usize) {
+        let num_items_to_place = num_bireachable + num_oneway_reachable;
+        let filtered_item_precedence: Vec<Item> = state
+            .item_precedence
+            .iter()
+            .copied()
+            .filter(|&item| {
+                state.items_remaining[item as usize] == self.initial_items_remaining[item as usize]
+            })
+            .collect();
+        let num_key_items_remaining = filtered_item_precedence.len();
+        let num_items_remaining: usize = state.items_remaining.iter().sum();
+        let mut num_key_items_to_place = match self.difficulty_tiers[0].progression_rate {
+            ProgressionRate::Slow => 1,
+            ProgressionRate::Uniform => usize::max(
+                1,
+                f32::round(
+                    (num_key_items_remaining as f32) / (num_items_remaining as f32)
+                        * (num_items_to_place as f32),
+                ) as usize,
+            ),
+            ProgressionRate::Fast => usize::max(
                 1,
                 f32::round(
                     2.0 * (num_key_items_remaining as f32) / (num_items_remaining as f32)
@@ -2970,6 +4104,56 @@ impl<'r> Randomizer<'r> {
         (num_key_items_to_place, num_filler_items_to_place)
     }
 
+    // Orders `items` by repeatedly sampling without replacement, weighted by
+    // `DifficultyConfig::filler_item_weights` (default 1.0 for an unlisted item type). Items are
+    // tracked as (item, weight, remaining count) groups rather than per-instance, so a draw only
+    // has to update one counter; the draw itself rolls against the group prefix-sum array. With
+    // all weights equal this is distributionally the same as a uniform `shuffle`.
+    fn weighted_order_items<R: Rng>(&self, items: &[Item], rng: &mut R) -> Vec<Item> {
+        let weight_for = |item: Item| -> f32 {
+            self.difficulty_tiers[0]
+                .filler_item_weights
+                .iter()
+                .find(|&&(w_item, _)| w_item == item)
+                .map(|&(_, w)| w.max(0.0))
+                .unwrap_or(1.0)
+        };
+
+        let mut groups: Vec<(Item, f32, usize)> = vec![];
+        for &item in items {
+            if let Some(g) = groups.iter_mut().find(|(i, _, _)| *i == item) {
+                g.2 += 1;
+            } else {
+                groups.push((item, weight_for(item), 1));
+            }
+        }
+
+        let mut result = Vec::with_capacity(items.len());
+        while !groups.is_empty() {
+            let prefix_sums: Vec<f32> = groups
+                .iter()
+                .scan(0.0, |acc, &(_, w, cnt)| {
+                    *acc += w * cnt as f32;
+                    Some(*acc)
+                })
+                .collect();
+            let total = *prefix_sums.last().unwrap();
+            let idx = if total > 0.0 {
+                let roll = rng.gen::<f32>() * total;
+                prefix_sums.partition_point(|&x| x <= roll).min(groups.len() - 1)
+            } else {
+                // All remaining groups have weight 0; fall back to uniform so they still get placed.
+                rng.gen_range(0..groups.len())
+            };
+            result.push(groups[idx].0);
+            groups[idx].2 -= 1;
+            if groups[idx].2 == 0 {
+                groups.remove(idx);
+            }
+        }
+        result
+    }
+
     fn select_filler_items<R: Rng>(
         &self,
         state: &RandomizationState,
@@ -3044,7 +4228,7 @@ impl<'r> Randomizer<'r> {
                 }
             }
         }
-        items_to_mix.shuffle(rng);
+        let items_to_mix = self.weighted_order_items(&items_to_mix, rng);
         let mut items_to_place: Vec<Item> = item_types_to_prioritize;
         items_to_place.extend(items_to_mix);
         items_to_place.extend(items_to_delay);
@@ -3169,7 +4353,7 @@ impl<'r> Randomizer<'r> {
 
             let traverse_result = traverse(
                 &self.base_links_data,
-                &self.seed_links_data,
+                &self.seed_links_data_tiers[tier],
                 self.get_init_traverse(state, init_traverse),
                 &tmp_global,
                 LocalState::new(),
@@ -3378,6 +4562,19 @@ impl<'r> Randomizer<'r> {
         (num_one_way_reachable < one_way_reachable_limit && gives_expansion) || is_beatable
     }
 
+    // Quality heuristic for a candidate post-selection state, used by `ItemPlacementStyle::Beam`
+    // to rank candidates: how many item and flag locations newly became bireachable, relative to
+    // `old_state`. Candidates that open up more of the map are preferred.
+    fn score_candidate(&self, old_state: &RandomizationState, new_state: &RandomizationState) -> usize {
+        let newly_bireachable_items = iter::zip(&new_state.item_location_state, &old_state.item_location_state)
+            .filter(|(n, o)| n.bireachable && !o.bireachable)
+            .count();
+        let newly_bireachable_flags = iter::zip(&new_state.flag_location_state, &old_state.flag_location_state)
+            .filter(|(n, o)| n.bireachable && !o.bireachable)
+            .count();
+        newly_bireachable_items + newly_bireachable_flags
+    }
+
     fn multi_attempt_select_items<R: Rng + Clone>(
         &self,
         attempt_num_rando: usize,
@@ -3426,6 +4623,55 @@ impl<'r> Randomizer<'r> {
             }
         }
 
+        // Skip this per-step item-selection beam when the coarser `randomize_beam` trajectory
+        // search (`beam_width > 1`) is already driving this attempt: that outer search branches
+        // this very step via decorrelated RNG and scores the resulting *states* directly (see
+        // `randomize_beam`), which already covers what re-scoring key-item candidates here would
+        // do, just at a wider granularity. Running both at once would stack two uncoordinated
+        // beam searches over the same decision with no way for either to see the other's
+        // candidates.
+        if self.difficulty_tiers[0].beam_width <= 1 {
+            if let ItemPlacementStyle::Beam { width } = self.difficulty_tiers[0].item_placement_style {
+                let mut best: Option<(usize, Vec<Item>, RandomizationState)> = None;
+                for attempt_num in 0..width.max(1) {
+                    let Some(candidate_key_items) =
+                        self.select_key_items(&new_state_filler, num_key_items_to_select, attempt_num)
+                    else {
+                        break;
+                    };
+                    let mut candidate_state = new_state_filler.clone();
+                    for &item in &candidate_key_items {
+                        if candidate_state.items_remaining[item as usize] > 0 {
+                            candidate_state.items_remaining[item as usize] -= 1;
+                        }
+                    }
+                    if !self.provides_progression(
+                        &state,
+                        &mut candidate_state,
+                        &candidate_key_items,
+                        &selected_filler_items,
+                        &placed_uncollected_bireachable_items,
+                        num_unplaced_bireachable,
+                    ) {
+                        continue;
+                    }
+                    let score = self.score_candidate(&state, &candidate_state);
+                    if best.as_ref().map_or(true, |(best_score, _, _)| score > *best_score) {
+                        best = Some((score, candidate_key_items, candidate_state));
+                    }
+                }
+                if let Some((_, selected_key_items, new_state)) = best {
+                    let selection = SelectItemsOutput {
+                        key_items: selected_key_items,
+                        other_items: selected_filler_items,
+                    };
+                    return (selection, new_state);
+                }
+                // Fall through to the sequential retry path below if no beam candidate panned out
+                // (e.g. because `width` candidates all failed to provide progression).
+            }
+        }
+
         let mut attempt_num = 0;
         let mut selected_key_items = self
             .select_key_items(&new_state_filler, num_key_items_to_select, attempt_num)
@@ -3547,7 +4793,11 @@ impl<'r> Randomizer<'r> {
                 if state.door_state[i].bireachable {
                     any_update = true;
                     let door_vertex_id = state.door_state[i].bireachable_vertex_id.unwrap();
-                    spoiler_door_summaries.push(self.get_spoiler_door_summary(door_vertex_id, i));
+                    spoiler_door_summaries.push(self.get_spoiler_door_summary(
+                        &state,
+                        door_vertex_id,
+                        i,
+                    ));
                     spoiler_door_details.push(self.get_spoiler_door_details(
                         &state,
                         door_vertex_id,
@@ -3820,6 +5070,57 @@ impl<'r> Randomizer<'r> {
             .collect();
         let spoiler_escape =
             escape_timer::compute_escape_data(self.game_data, self.map, &self.difficulty_tiers[0])?;
+        let key_unlock_analysis = self.compute_key_unlock_analysis(state);
+        let spoiler_key_unlocks = state
+            .item_location_state
+            .iter()
+            .enumerate()
+            .filter_map(|(i, x)| {
+                let (keys, cost) = key_unlock_analysis[i].as_ref()?;
+                let (r, n) = self.game_data.item_locations[i];
+                let item_vertex_info = self.get_vertex_info_by_id(r, n);
+                let location = SpoilerLocation {
+                    area: item_vertex_info.area_name,
+                    room: item_vertex_info.room_name,
+                    node: item_vertex_info.node_name,
+                    coords: item_vertex_info.room_coords,
+                };
+                let item = x.placed_item.unwrap();
+                Some(SpoilerKeyUnlock {
+                    item: Item::VARIANTS[item as usize].to_string(),
+                    location,
+                    required_keys: keys.iter().map(|k| Item::VARIANTS[*k as usize].to_string()).collect(),
+                    cost: *cost,
+                })
+            })
+            .collect();
+        let critical_path = self.compute_critical_path_analysis(state);
+        let spoiler_required_items: Vec<SpoilerRequiredItem> = critical_path
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(step, item)| {
+                let i = state
+                    .item_location_state
+                    .iter()
+                    .position(|x| x.placed_item == Some(item))?;
+                let (r, n) = self.game_data.item_locations[i];
+                let item_vertex_info = self.get_vertex_info_by_id(r, n);
+                let location = SpoilerLocation {
+                    area: item_vertex_info.area_name,
+                    room: item_vertex_info.room_name,
+                    node: item_vertex_info.node_name,
+                    coords: item_vertex_info.room_coords,
+                };
+                Some(SpoilerRequiredItem {
+                    item: Item::VARIANTS[item as usize].to_string(),
+                    location,
+                    step: step + 1,
+                })
+            })
+            .collect();
+        let dependency_graph = self.compute_dependency_graph(&spoiler_summaries);
+        let route_metrics = self.compute_route_metrics(&spoiler_details);
         let spoiler_log = SpoilerLog {
             item_priority: state
                 .item_precedence
@@ -3831,6 +5132,10 @@ impl<'r> Randomizer<'r> {
             details: spoiler_details,
             all_items: spoiler_all_items,
             all_rooms: spoiler_all_rooms,
+            key_unlocks: spoiler_key_unlocks,
+            required_items: spoiler_required_items,
+            dependency_graph,
+            route_metrics,
         };
 
         Ok(Randomization {
@@ -3854,6 +5159,30 @@ impl<'r> Randomizer<'r> {
         item_priority_strength: ItemPriorityStrength,
         rng: &mut R,
     ) -> Vec<Item> {
+        if item_priority_strength == ItemPriorityStrength::Weighted {
+            // The weighted table (`DifficultyConfig::item_weights`) already encodes where
+            // Missile/Nothing and the other special cases below should land via per-phase
+            // multipliers, so it replaces the fixed group shuffle and the progression-rate pushes
+            // that otherwise surround it.
+            let mut items: Vec<Item> = vec![];
+            for priority_group in item_priorities {
+                for item_name in &priority_group.items {
+                    let item_idx = self.game_data.item_isv.index_by_key[item_name];
+                    let item = Item::try_from(item_idx).unwrap();
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+            }
+            if !items.contains(&Item::Missile) {
+                items.push(Item::Missile);
+            }
+            if !items.contains(&Item::Nothing) {
+                items.push(Item::Nothing);
+            }
+            return self.weighted_item_precedence(&items, rng);
+        }
+
         let mut item_precedence: Vec<Item> = Vec::new();
         if self.difficulty_tiers[0].progression_rate == ProgressionRate::Slow {
             // With slow progression, prioritize placing nothing and missiles over other key items.
@@ -3904,6 +5233,7 @@ impl<'r> Randomizer<'r> {
                     }
                 }
             }
+            ItemPriorityStrength::Weighted => unreachable!("handled by early return above"),
         }
         if self.difficulty_tiers[0].progression_rate != ProgressionRate::Slow {
             // With Normal and Uniform progression, prioritize all other key items over missiles
@@ -3914,6 +5244,62 @@ impl<'r> Randomizer<'r> {
         item_precedence
     }
 
+    // Orders `items` by repeated weighted sampling without replacement, the same technique as
+    // `weighted_order_items`, except the weight used for each draw is additionally scaled by an
+    // early/mid/late phase multiplier (from `DifficultyConfig::item_weights`) depending on what
+    // fraction of `items` has already been placed into the result. This is what
+    // `ItemPriorityStrength::Weighted` uses instead of the fixed three-group shuffle.
+    fn weighted_item_precedence<R: Rng>(&self, items: &[Item], rng: &mut R) -> Vec<Item> {
+        let entry_for = |item: Item| -> Option<&ItemWeightEntry> {
+            self.difficulty_tiers[0]
+                .item_weights
+                .iter()
+                .find(|e| e.item == item)
+        };
+        let weight_for = |item: Item, frac: f32| -> f32 {
+            let Some(entry) = entry_for(item) else {
+                return 1.0;
+            };
+            let mult = if frac < 1.0 / 3.0 {
+                entry.early_mult
+            } else if frac < 2.0 / 3.0 {
+                entry.mid_mult
+            } else {
+                entry.late_mult
+            };
+            (entry.weight * mult).max(0.0)
+        };
+
+        let mut remaining = items.to_vec();
+        let total = remaining.len().max(1) as f32;
+        let mut result = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            let frac = result.len() as f32 / total;
+            let weights: Vec<f32> = remaining
+                .iter()
+                .map(|&item| weight_for(item, frac))
+                .collect();
+            let total_weight: f32 = weights.iter().sum();
+            let idx = if total_weight > 0.0 {
+                let roll = rng.gen::<f32>() * total_weight;
+                let prefix_sums: Vec<f32> = weights
+                    .iter()
+                    .scan(0.0, |acc, &w| {
+                        *acc += w;
+                        Some(*acc)
+                    })
+                    .collect();
+                prefix_sums
+                    .partition_point(|&x| x <= roll)
+                    .min(remaining.len() - 1)
+            } else {
+                rng.gen_range(0..remaining.len())
+            };
+            result.push(remaining.remove(idx));
+        }
+        result
+    }
+
     fn rerandomize_tank_precedence<R: Rng>(&self, item_precedence: &mut [Item], rng: &mut R) {
         if rng.gen_bool(0.5) {
             return;
@@ -3936,12 +5322,188 @@ impl<'r> Randomizer<'r> {
         if spazer_idx_opt.is_none() || plasma_idx_opt.is_none() {
             return;
         }
-        let spazer_idx = spazer_idx_opt.unwrap();
-        let plasma_idx = plasma_idx_opt.unwrap();
-        if plasma_idx < spazer_idx {
-            item_precedence[plasma_idx] = Item::Spazer;
-            item_precedence[spazer_idx] = Item::Plasma;
+        let spazer_idx = spazer_idx_opt.unwrap();
+        let plasma_idx = plasma_idx_opt.unwrap();
+        if plasma_idx < spazer_idx {
+            item_precedence[plasma_idx] = Item::Spazer;
+            item_precedence[spazer_idx] = Item::Plasma;
+        }
+    }
+
+    // A start location candidate's reachability, precomputed once by
+    // `build_start_location_candidates` so the `Greedy`/`Scored` ranking heuristic and the
+    // hub-matching scan both reuse the same three `traverse` calls instead of repeating them per
+    // attempt, the way the plain `Random` loop otherwise would.
+    fn build_start_location_candidates(&self) -> Vec<StartLocationCandidate> {
+        let num_vertices = self.game_data.vertex_isv.keys.len();
+        self.game_data
+            .start_locations
+            .iter()
+            .filter_map(|start_loc| {
+                let start_loc = start_loc.clone();
+                let start_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
+                    room_id: start_loc.room_id,
+                    node_id: start_loc.node_id,
+                    obstacle_mask: 0,
+                    actions: vec![],
+                }];
+                let global = self.get_initial_global_state();
+                let local = apply_requirement(
+                    start_loc.requires_parsed.as_ref().unwrap(),
+                    &global,
+                    LocalState::new(),
+                    false,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    &self.locked_door_data,
+                )?;
+                let forward = traverse(
+                    &self.base_links_data,
+                    &self.seed_links_data,
+                    None,
+                    &global,
+                    local,
+                    num_vertices,
+                    start_vertex_id,
+                    false,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    self.locked_door_data,
+                );
+                let forward0 = traverse(
+                    &self.base_links_data,
+                    &self.seed_links_data,
+                    None,
+                    &global,
+                    LocalState::new(),
+                    num_vertices,
+                    start_vertex_id,
+                    false,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    self.locked_door_data,
+                );
+                let reverse = traverse(
+                    &self.base_links_data,
+                    &self.seed_links_data,
+                    None,
+                    &global,
+                    LocalState::new(),
+                    num_vertices,
+                    start_vertex_id,
+                    true,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    self.locked_door_data,
+                );
+                Some(StartLocationCandidate {
+                    start_loc,
+                    global,
+                    forward,
+                    forward0,
+                    reverse,
+                })
+            })
+            .collect()
+    }
+
+    // Number of hub locations one-way reachable from this candidate (a cheap proxy for how many
+    // hub-matching attempts would succeed) plus a smaller bonus for how many distinct areas are
+    // reachable at all from it, favoring starts that open up the map rather than being stuck in
+    // one corner. Higher is better.
+    fn score_start_location_candidate(&self, candidate: &StartLocationCandidate) -> f32 {
+        let num_reachable_hubs = self
+            .game_data
+            .hub_locations
+            .iter()
+            .filter(|hub| {
+                let hub_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
+                    room_id: hub.room_id,
+                    node_id: hub.node_id,
+                    obstacle_mask: 0,
+                    actions: vec![],
+                }];
+                candidate.forward.cost[hub_vertex_id]
+                    .iter()
+                    .any(|&x| f32::is_finite(x))
+            })
+            .count();
+        let distinct_areas: HashSet<String> = (0..candidate.forward0.cost.len())
+            .filter(|&v| candidate.forward0.cost[v].iter().any(|&x| f32::is_finite(x)))
+            .map(|v| self.get_vertex_info(v).area_name)
+            .collect();
+        num_reachable_hubs as f32 + 0.5 * distinct_areas.len() as f32
+    }
+
+    // Finds the first hub location satisfying the same three conditions as the hub-matching loop
+    // in `determine_start_location`'s `Random` mode, reusing a precomputed candidate's reachability
+    // instead of recomputing it.
+    fn find_hub_for_candidate(&self, candidate: &StartLocationCandidate) -> Option<HubLocation> {
+        for hub in &self.game_data.hub_locations {
+            let hub_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
+                room_id: hub.room_id,
+                node_id: hub.node_id,
+                obstacle_mask: 0,
+                actions: vec![],
+            }];
+            if candidate.forward.cost[hub_vertex_id]
+                .iter()
+                .any(|&x| f32::is_finite(x))
+                && get_bireachable_idxs(
+                    &candidate.global,
+                    hub_vertex_id,
+                    &candidate.forward0,
+                    &candidate.reverse,
+                )
+                .is_some()
+            {
+                let local = apply_requirement(
+                    hub.requires_parsed.as_ref().unwrap(),
+                    &candidate.global,
+                    LocalState::new(),
+                    false,
+                    &self.difficulty_tiers[0],
+                    self.game_data,
+                    &self.locked_door_data,
+                );
+                if local.is_some() {
+                    return Some(hub.clone());
+                }
+            }
+        }
+        None
+    }
+
+    // `Greedy`/`Scored` start-location selection: rank every candidate once via
+    // `score_start_location_candidate` (`Scored` shuffles ties first so repeated generations
+    // aren't always identical) and try them highest-score-first, falling back down the ranked
+    // list the same way `Random`'s loop falls back through random draws.
+    fn determine_start_location_ranked<R: Rng>(
+        &self,
+        attempt_num_rando: usize,
+        num_attempts: usize,
+        rng: &mut R,
+    ) -> Result<(StartLocation, HubLocation)> {
+        let mut scored: Vec<(f32, StartLocationCandidate)> = self
+            .build_start_location_candidates()
+            .into_iter()
+            .map(|c| (self.score_start_location_candidate(&c), c))
+            .collect();
+        if self.difficulty_tiers[0].start_location_mode == StartLocationMode::Scored {
+            scored.shuffle(rng);
+        }
+        scored.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap());
+
+        for (i, (score, candidate)) in scored.iter().take(num_attempts.max(1)).enumerate() {
+            info!(
+                "[attempt {attempt_num_rando}] start location attempt {i}, score={score}, start: {:?}",
+                candidate.start_loc
+            );
+            if let Some(hub) = self.find_hub_for_candidate(candidate) {
+                return Ok((candidate.start_loc.clone(), hub));
+            }
         }
+        bail!("[attempt {attempt_num_rando}] Failed to find start location.")
     }
 
     pub fn determine_start_location<R: Rng>(
@@ -3966,6 +5528,12 @@ impl<'r> Randomizer<'r> {
 
             return Ok((ship_start, ship_hub));
         }
+        if matches!(
+            self.difficulty_tiers[0].start_location_mode,
+            StartLocationMode::Greedy | StartLocationMode::Scored
+        ) {
+            return self.determine_start_location_ranked(attempt_num_rando, num_attempts, rng);
+        }
         for i in 0..num_attempts {
             info!("[attempt {attempt_num_rando}] start location attempt {}", i);
             let start_loc_idx = rng.gen_range(0..self.game_data.start_locations.len());
@@ -4152,6 +5720,13 @@ impl<'r> Randomizer<'r> {
             details: vec![],
             all_items: vec![],
             all_rooms: spoiler_all_rooms,
+            key_unlocks: vec![],
+            required_items: vec![],
+            dependency_graph: SpoilerDependencyGraph { nodes: vec![] },
+            route_metrics: SpoilerRouteMetrics {
+                total_steps: 0,
+                area_breakdown: vec![],
+            },
         };
         Ok(Randomization {
             difficulty: self.difficulty_tiers[0].clone(),
@@ -4179,6 +5754,21 @@ impl<'r> Randomizer<'r> {
         return false;
     }
 
+    // Runs `randomize` for each `(attempt_num_rando, seed, display_seed)` tuple concurrently via
+    // rayon, so a server generating many seeds off of the same `Randomizer` (map + difficulty
+    // tiers) saturates cores instead of generating one seed at a time.
+    pub fn randomize_batch(
+        &self,
+        requests: &[(usize, usize, usize)],
+    ) -> Vec<Result<Randomization>> {
+        requests
+            .par_iter()
+            .map(|&(attempt_num_rando, seed, display_seed)| {
+                self.randomize(attempt_num_rando, seed, display_seed)
+            })
+            .collect()
+    }
+
     pub fn randomize(
         &self,
         attempt_num_rando: usize,
@@ -4188,6 +5778,9 @@ impl<'r> Randomizer<'r> {
         if self.difficulty_tiers[0].start_location_mode == StartLocationMode::Escape {
             return self.dummy_randomize(seed, display_seed);
         }
+        if self.difficulty_tiers[0].beam_width > 1 {
+            return self.randomize_beam(attempt_num_rando, seed, display_seed);
+        }
         let mut rng_seed = [0u8; 32];
         rng_seed[..8].copy_from_slice(&seed.to_le_bytes());
         let mut rng = rand::rngs::StdRng::from_seed(rng_seed);
@@ -4199,17 +5792,21 @@ impl<'r> Randomizer<'r> {
             bireachable: false,
             bireachable_vertex_id: None,
             difficulty_tier: None,
+            first_bireachable_step: None,
         };
         let initial_flag_location_state = FlagLocationState {
             reachable: false,
             reachable_vertex_id: None,
             bireachable: false,
             bireachable_vertex_id: None,
+            first_reachable_step: None,
+            first_bireachable_step: None,
         };
         let initial_save_location_state = SaveLocationState { bireachable: false };
         let initial_door_state = DoorState {
             bireachable: false,
             bireachable_vertex_id: None,
+            first_bireachable_step: None,
         };
         let num_attempts_start_location = 10;
         let (start_location, hub_location) = self.determine_start_location(
@@ -4353,11 +5950,258 @@ impl<'r> Randomizer<'r> {
             display_seed,
         )
     }
+
+    // Classifies the outcome of a step taken by a single beam-search candidate, mirroring the
+    // `!any_progress` bookkeeping in `randomize`'s single-state loop but returning an outcome
+    // instead of bailing out of the whole attempt: a beam candidate that fails this check is just
+    // dropped from the beam rather than failing the entire `randomize` attempt.
+    fn classify_beam_step(&self, state: &RandomizationState, any_progress: bool) -> BeamStepOutcome {
+        if !any_progress {
+            if !self.is_game_beatable(state) {
+                return BeamStepOutcome::Failed;
+            }
+            if !self.difficulty_tiers[0].stop_item_placement_early {
+                for i in 0..self.initial_items_remaining.len() {
+                    if self.initial_items_remaining[i] > 0 && !state.global_state.items[i] {
+                        return BeamStepOutcome::Failed;
+                    }
+                }
+                let phantoon_flag_id = self.game_data.flag_isv.index_by_key["f_DefeatedPhantoon"];
+                let phantoon_defeated = self
+                    .game_data
+                    .flag_ids
+                    .iter()
+                    .enumerate()
+                    .any(|(i, &flag_id)| {
+                        flag_id == phantoon_flag_id && state.flag_location_state[i].bireachable
+                    });
+                if !phantoon_defeated {
+                    return BeamStepOutcome::Failed;
+                }
+            }
+            return BeamStepOutcome::Succeeded;
+        }
+        if state.step_num == 1
+            && self.difficulty_tiers[0].early_save
+            && !state.save_location_state.iter().any(|x| x.bireachable)
+        {
+            return BeamStepOutcome::Failed;
+        }
+        BeamStepOutcome::Continue
+    }
+
+    // Beam-search item placement: instead of following a single `RandomizationState` greedily
+    // (re-rolling the whole attempt on a dead end, as `randomize` does), keeps a beam of up to
+    // `beam_width` candidate partial states. Each step, every live candidate is branched into up
+    // to `beam_width` children by re-running `step` with decorrelated RNG draws, each child is
+    // scored by a heuristic (how much newly-bireachable progress it made, spread across how many
+    // distinct areas, with a large bonus once it reaches the attempt's finish line), and only the
+    // top-scoring `beam_width` children survive into the next round. Each candidate carries its
+    // own full spoiler history forward (rather than separate parent pointers), so once a candidate
+    // finishes, its accumulated spoiler vectors are already the complete record for that lineage.
+    fn randomize_beam(
+        &self,
+        attempt_num_rando: usize,
+        seed: usize,
+        display_seed: usize,
+    ) -> Result<Randomization> {
+        let beam_width = self.difficulty_tiers[0].beam_width;
+        let mut rng_seed = [0u8; 32];
+        rng_seed[..8].copy_from_slice(&seed.to_le_bytes());
+        let mut rng = rand::rngs::StdRng::from_seed(rng_seed);
+        let initial_global_state = self.get_initial_global_state();
+        let initial_item_location_state = ItemLocationState {
+            placed_item: None,
+            collected: false,
+            reachable: false,
+            bireachable: false,
+            bireachable_vertex_id: None,
+            difficulty_tier: None,
+            first_bireachable_step: None,
+        };
+        let initial_flag_location_state = FlagLocationState {
+            reachable: false,
+            reachable_vertex_id: None,
+            bireachable: false,
+            bireachable_vertex_id: None,
+            first_reachable_step: None,
+            first_bireachable_step: None,
+        };
+        let initial_save_location_state = SaveLocationState { bireachable: false };
+        let initial_door_state = DoorState {
+            bireachable: false,
+            bireachable_vertex_id: None,
+            first_bireachable_step: None,
+        };
+        let num_attempts_start_location = 10;
+        let (start_location, hub_location) = self.determine_start_location(
+            attempt_num_rando,
+            num_attempts_start_location,
+            &mut rng,
+        )?;
+        let mut item_precedence: Vec<Item> = self.get_item_precedence(
+            &self.difficulty_tiers[0].item_priorities,
+            self.difficulty_tiers[0].item_priority_strength,
+            &mut rng,
+        );
+        if self.difficulty_tiers[0].spazer_before_plasma {
+            self.apply_spazer_plasma_priority(&mut item_precedence);
+        }
+        info!(
+            "[attempt {attempt_num_rando}] Item precedence: {:?}",
+            item_precedence
+        );
+        let mut state = RandomizationState {
+            step_num: 1,
+            item_precedence,
+            start_location,
+            hub_location,
+            item_location_state: vec![
+                initial_item_location_state;
+                self.game_data.item_locations.len()
+            ],
+            flag_location_state: vec![initial_flag_location_state; self.game_data.flag_ids.len()],
+            save_location_state: vec![
+                initial_save_location_state;
+                self.game_data.save_locations.len()
+            ],
+            door_state: vec![initial_door_state; self.locked_door_data.locked_doors.len()],
+            items_remaining: self.initial_items_remaining.clone(),
+            global_state: initial_global_state,
+            debug_data: None,
+            previous_debug_data: None,
+            key_visited_vertices: HashSet::new(),
+        };
+        self.update_reachability(&mut state);
+        if !state.item_location_state.iter().any(|x| x.bireachable) {
+            bail!("[attempt {attempt_num_rando}] No initially bireachable item locations");
+        }
+
+        struct BeamCandidate {
+            state: RandomizationState,
+            rng: rand::rngs::StdRng,
+            spoiler_summary_vec: Vec<SpoilerSummary>,
+            spoiler_details_vec: Vec<SpoilerDetails>,
+            debug_data_vec: Vec<DebugData>,
+        }
+
+        let mut beam = vec![BeamCandidate {
+            state,
+            rng,
+            spoiler_summary_vec: Vec::new(),
+            spoiler_details_vec: Vec::new(),
+            debug_data_vec: Vec::new(),
+        }];
+
+        let mut winner = loop {
+            let mut children: Vec<(f32, BeamCandidate)> = Vec::new();
+            for parent in beam {
+                for branch in 0..beam_width {
+                    let mut child_state = parent.state.clone();
+                    let mut child_rng = parent.rng.clone();
+                    // Decorrelate sibling branches that start from the same parent RNG state.
+                    for _ in 0..branch {
+                        child_rng.gen::<u64>();
+                    }
+                    if self.difficulty_tiers[0].random_tank {
+                        self.rerandomize_tank_precedence(&mut child_state.item_precedence, &mut child_rng);
+                    }
+                    let (spoiler_summary, spoiler_details, is_early_stop) =
+                        self.step(attempt_num_rando, &mut child_state, &mut child_rng);
+
+                    let newly_open =
+                        (spoiler_summary.items.len() + spoiler_summary.flags.len()) as f32;
+                    let distinct_areas = spoiler_summary
+                        .items
+                        .iter()
+                        .map(|x| x.location.area.as_str())
+                        .collect::<HashSet<_>>()
+                        .len() as f32;
+                    let any_progress =
+                        !spoiler_summary.items.is_empty() || !spoiler_summary.flags.is_empty();
+
+                    let outcome = if is_early_stop {
+                        BeamStepOutcome::Succeeded
+                    } else {
+                        self.classify_beam_step(&child_state, any_progress)
+                    };
+
+                    if let BeamStepOutcome::Continue = outcome {
+                        child_state.step_num += 1;
+                    }
+
+                    if matches!(outcome, BeamStepOutcome::Failed) {
+                        continue;
+                    }
+
+                    let score = newly_open
+                        + 0.5 * distinct_areas
+                        + if matches!(outcome, BeamStepOutcome::Succeeded) {
+                            1e6
+                        } else {
+                            0.0
+                        };
+
+                    let mut spoiler_summary_vec = parent.spoiler_summary_vec.clone();
+                    let mut spoiler_details_vec = parent.spoiler_details_vec.clone();
+                    let mut debug_data_vec = parent.debug_data_vec.clone();
+                    spoiler_summary_vec.push(spoiler_summary);
+                    spoiler_details_vec.push(spoiler_details);
+                    debug_data_vec.push(child_state.previous_debug_data.as_ref().unwrap().clone());
+
+                    children.push((
+                        score,
+                        BeamCandidate {
+                            state: child_state,
+                            rng: child_rng,
+                            spoiler_summary_vec,
+                            spoiler_details_vec,
+                            debug_data_vec,
+                        },
+                    ));
+
+                    if matches!(outcome, BeamStepOutcome::Succeeded) {
+                        // This parent is done; no need to branch further on it.
+                        break;
+                    }
+                }
+            }
+
+            if children.is_empty() {
+                bail!(
+                    "[attempt {attempt_num_rando}] Beam search attempt failed: no surviving candidates"
+                );
+            }
+
+            if let Some(pos) = children.iter().position(|&(score, _)| score >= 1e6) {
+                break children.swap_remove(pos).1;
+            }
+
+            children.sort_by(|(a, _), (b, _)| b.partial_cmp(a).unwrap());
+            children.truncate(beam_width);
+            info!(
+                "[attempt {attempt_num_rando}] beam step, candidates={}, top score={}",
+                children.len(),
+                children[0].0
+            );
+            beam = children.into_iter().map(|(_, c)| c).collect();
+        };
+
+        self.finish(attempt_num_rando, &mut winner.state);
+        self.get_randomization(
+            &winner.state,
+            winner.spoiler_summary_vec,
+            winner.spoiler_details_vec,
+            winner.debug_data_vec,
+            seed,
+            display_seed,
+        )
+    }
 }
 
 // Spoiler log ---------------------------------------------------------
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct SpoilerRouteEntry {
     area: String,
     room: String,
@@ -4384,7 +6228,7 @@ pub struct SpoilerRouteEntry {
     power_bombs_used: Option<Capacity>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerLocation {
     pub area: String,
     pub room: String,
@@ -4392,7 +6236,7 @@ pub struct SpoilerLocation {
     pub coords: (usize, usize),
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerStartState {
     max_energy: Capacity,
     max_reserves: Capacity,
@@ -4403,7 +6247,7 @@ pub struct SpoilerStartState {
     flags: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerItemDetails {
     item: String,
     location: SpoilerLocation,
@@ -4412,7 +6256,7 @@ pub struct SpoilerItemDetails {
     return_route: Vec<SpoilerRouteEntry>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerFlagDetails {
     flag: String,
     location: SpoilerLocation,
@@ -4420,15 +6264,16 @@ pub struct SpoilerFlagDetails {
     return_route: Vec<SpoilerRouteEntry>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerDoorDetails {
     door_type: String,
+    weapon: String,
     location: SpoilerLocation,
     obtain_route: Vec<SpoilerRouteEntry>,
     return_route: Vec<SpoilerRouteEntry>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerDetails {
     step: usize,
     start_state: SpoilerStartState,
@@ -4453,29 +6298,53 @@ pub struct SpoilerRoomLoc {
     coords: (usize, usize),
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerItemSummary {
     pub item: String,
     pub location: SpoilerLocation,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerFlagSummary {
     flag: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerDoorSummary {
     door_type: String,
+    weapon: String,
     location: SpoilerLocation,
+    obtain_route: Vec<SpoilerRouteEntry>,
+    return_route: Vec<SpoilerRouteEntry>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SpoilerSummary {
     pub step: usize,
     pub flags: Vec<SpoilerFlagSummary>,
     pub doors: Vec<SpoilerDoorSummary>,
     pub items: Vec<SpoilerItemSummary>,
+    // Every item location, flag, and door that became bireachable this step, regardless of
+    // whether the placement algorithm actually collected it (see `flags`/`doors`/`items` above).
+    // In today's placement algorithm these end up closely tracking the collected sets (bireachable
+    // locations are always filled the same step), but this exposes the full reachability frontier
+    // independent of that placement choice, for logic-debugging.
+    pub reachable: SpoilerReachable,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SpoilerReachable {
+    pub items: Vec<SpoilerItemSummary>,
+    pub flags: Vec<SpoilerFlagSummary>,
+    pub doors: Vec<SpoilerDoorSummary>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SpoilerKeyUnlock {
+    pub item: String,
+    pub location: SpoilerLocation,
+    pub required_keys: Vec<String>,
+    pub cost: usize,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -4486,6 +6355,63 @@ pub struct SpoilerLog {
     pub details: Vec<SpoilerDetails>,
     pub all_items: Vec<SpoilerItemLoc>,
     pub all_rooms: Vec<SpoilerRoomLoc>,
+    // Minimum door-unlock "keys" (and an abstract cost) needed to reach each item, per
+    // `compute_key_unlock_analysis`. Items unreachable even with every key (shouldn't happen in a
+    // completable seed) or not yet placed are omitted.
+    pub key_unlocks: Vec<SpoilerKeyUnlock>,
+    // A minimal set of items that are logically required to beat the game, per
+    // `compute_critical_path_analysis`, listed in the order that search added them; this is not
+    // necessarily an order in which they can be collected, and each entry's `step` is its 1-based
+    // position in this list, not a playthrough step. Locations not listed here are logically
+    // optional (filler). Empty if no completing path was found or there are more than 12 key items.
+    pub required_items: Vec<SpoilerRequiredItem>,
+    // Directed prerequisite graph over items/flags/doors, per `compute_dependency_graph`, for
+    // external trackers/visualizers to render the logical progression and detect chokepoints.
+    pub dependency_graph: SpoilerDependencyGraph,
+    // Estimated length of the playthrough route implied by `details`, per `compute_route_metrics`.
+    pub route_metrics: SpoilerRouteMetrics,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SpoilerDependencyGraph {
+    pub nodes: Vec<SpoilerDependencyNode>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SpoilerDependencyNode {
+    pub id: String,
+    pub kind: String,
+    pub name: String,
+    pub step: usize,
+    pub prerequisites: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SpoilerRequiredItem {
+    pub item: String,
+    pub location: SpoilerLocation,
+    pub step: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SpoilerAreaSteps {
+    pub area: String,
+    pub steps: usize,
+}
+
+// Estimated length of the route a player following the spoiler in order would walk, measured in
+// the resource cost (energy + reserves + missiles + supers + power bombs spent) that
+// `get_spoiler_route` already tracks per hop via `local_state`, rather than a raw hop count, since
+// that's the actual "route cost" the traversal search optimizes against. `total_steps` sums every
+// obtain/return route across every step of `details` in playthrough order; `area_breakdown` is the
+// same total split out by the area each hop lands in, for spotting which areas account for the
+// most backtracking. It is measured on the one route the placement search happened to record
+// (minimized only when `optimize_spoiler_routes` is set), so it is an upper bound on the cheapest
+// route, not a re-optimized ordering across locations collected in the same step.
+#[derive(Serialize, Deserialize)]
+pub struct SpoilerRouteMetrics {
+    pub total_steps: usize,
+    pub area_breakdown: Vec<SpoilerAreaSteps>,
 }
 
 impl<'a> Randomizer<'a> {
@@ -4676,6 +6602,87 @@ impl<'a> Randomizer<'a> {
         route
     }
 
+    // Finds a minimum-resource-cost route from the current hub location to `target_vertex_id`,
+    // by running Dijkstra's algorithm directly over the `Link` graph (rather than reconstructing
+    // whatever trail the bulk `traverse` fixpoint happened to settle on first). Edge cost is the
+    // resource expenditure (`apply_link`'s before/after `LocalState` difference) plus a small
+    // per-link step penalty, so the search favors fewer, cheaper hops. Returns `None` if
+    // `target_vertex_id` isn't reachable from `start_vertex_id` at all.
+    fn get_shortest_spoiler_route(
+        &self,
+        global_state: &GlobalState,
+        start_vertex_id: VertexId,
+        target_vertex_id: VertexId,
+        difficulty: &DifficultyConfig,
+        reverse: bool,
+    ) -> Option<Vec<LinkIdx>> {
+        const STEP_PENALTY: Capacity = 1;
+
+        let num_total_links =
+            (self.base_links_data.links.len() + self.seed_links_data.links.len()) as LinkIdx;
+        let mut best_cost: HashMap<VertexId, Capacity> = HashMap::new();
+        let mut best_local_state: HashMap<VertexId, LocalState> = HashMap::new();
+        let mut came_from: HashMap<VertexId, LinkIdx> = HashMap::new();
+        let mut heap: BinaryHeap<Reverse<(Capacity, VertexId)>> = BinaryHeap::new();
+
+        best_cost.insert(start_vertex_id, 0);
+        best_local_state.insert(start_vertex_id, LocalState::new());
+        heap.push(Reverse((0, start_vertex_id)));
+
+        while let Some(Reverse((cost, vertex_id))) = heap.pop() {
+            if vertex_id == target_vertex_id {
+                let mut path = vec![];
+                let mut v = target_vertex_id;
+                while v != start_vertex_id {
+                    let link_idx = *came_from.get(&v)?;
+                    path.push(link_idx);
+                    v = self.get_link(link_idx as usize).from_vertex_id;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if cost > best_cost[&vertex_id] {
+                continue; // Stale heap entry, superseded by a cheaper one already settled.
+            }
+            let local_state = best_local_state[&vertex_id].clone();
+            for link_idx in 0..num_total_links {
+                let link = self.get_link(link_idx as usize);
+                if link.from_vertex_id != vertex_id {
+                    continue;
+                }
+                let Some(new_local_state) = apply_link(
+                    link,
+                    global_state,
+                    local_state,
+                    reverse,
+                    difficulty,
+                    self.game_data,
+                    &self.locked_door_data,
+                ) else {
+                    continue;
+                };
+                let used_before = local_state.energy_used
+                    + local_state.reserves_used
+                    + local_state.missiles_used
+                    + local_state.supers_used
+                    + local_state.power_bombs_used;
+                let used_after = new_local_state.energy_used
+                    + new_local_state.reserves_used
+                    + new_local_state.missiles_used
+                    + new_local_state.supers_used
+                    + new_local_state.power_bombs_used;
+                let new_cost = cost + Capacity::max(0, used_after - used_before) + STEP_PENALTY;
+                if new_cost < *best_cost.get(&link.to_vertex_id).unwrap_or(&Capacity::MAX) {
+                    best_cost.insert(link.to_vertex_id, new_cost);
+                    best_local_state.insert(link.to_vertex_id, new_local_state);
+                    came_from.insert(link.to_vertex_id, link_idx);
+                    heap.push(Reverse((new_cost, link.to_vertex_id)));
+                }
+            }
+        }
+        None
+    }
+
     fn get_spoiler_route_birectional(
         &self,
         state: &RandomizationState,
@@ -4686,10 +6693,37 @@ impl<'a> Randomizer<'a> {
         let global_state = &state.debug_data.as_ref().unwrap().global_state;
         let (forward_cost_idx, reverse_cost_idx) =
             get_bireachable_idxs(global_state, vertex_id, forward, reverse).unwrap();
-        let forward_link_idxs: Vec<LinkIdx> =
-            get_spoiler_route(forward, vertex_id, forward_cost_idx);
-        let reverse_link_idxs: Vec<LinkIdx> =
-            get_spoiler_route(reverse, vertex_id, reverse_cost_idx);
+        let start_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
+            room_id: state.hub_location.room_id,
+            node_id: state.hub_location.node_id,
+            obstacle_mask: 0,
+            actions: vec![],
+        }];
+        let optimize = self.difficulty_tiers[0].optimize_spoiler_routes;
+        let forward_link_idxs: Vec<LinkIdx> = if optimize {
+            self.get_shortest_spoiler_route(
+                global_state,
+                start_vertex_id,
+                vertex_id,
+                &self.difficulty_tiers[0],
+                false,
+            )
+            .unwrap_or_else(|| get_spoiler_route(forward, vertex_id, forward_cost_idx))
+        } else {
+            get_spoiler_route(forward, vertex_id, forward_cost_idx)
+        };
+        let reverse_link_idxs: Vec<LinkIdx> = if optimize {
+            self.get_shortest_spoiler_route(
+                global_state,
+                start_vertex_id,
+                vertex_id,
+                &self.difficulty_tiers[0],
+                true,
+            )
+            .unwrap_or_else(|| get_spoiler_route(reverse, vertex_id, reverse_cost_idx))
+        } else {
+            get_spoiler_route(reverse, vertex_id, reverse_cost_idx)
+        };
         let obtain_route = self.get_spoiler_route(
             global_state,
             LocalState::new(),
@@ -4817,22 +6851,35 @@ impl<'a> Randomizer<'a> {
         }
     }
 
+    // Which weapon/ammo class breaks a given door type, mirroring `door_key_item`'s mapping but as
+    // a display string for the spoiler rather than an `Item` used in reachability analysis. Doors
+    // with no single-item key (blue, gray, combat-lock, map-revealer, objective) report "none".
+    fn get_door_weapon_name(door_type: DoorType) -> String {
+        match door_key_item(door_type) {
+            Some(item) => Item::VARIANTS[item as usize].to_string(),
+            None => "none".to_string(),
+        }
+    }
+
     fn get_door_type_name(door_type: DoorType) -> String {
         match door_type {
-            DoorType::Blue => "blue",
-            DoorType::Red => "red",
-            DoorType::Green => "green",
-            DoorType::Yellow => "yellow",
-            DoorType::Gray => "gray",
+            DoorType::Blue => "blue".to_string(),
+            DoorType::Red => "red".to_string(),
+            DoorType::Green => "green".to_string(),
+            DoorType::Yellow => "yellow".to_string(),
+            DoorType::Gray => "gray".to_string(),
             DoorType::Beam(beam) => match beam {
                 BeamType::Charge => "charge",
                 BeamType::Ice => "ice",
                 BeamType::Wave => "wave",
                 BeamType::Spazer => "spazer",
                 BeamType::Plasma => "plasma",
-            },
+            }
+            .to_string(),
+            DoorType::Objective { count } => format!("objective-{}", count),
+            DoorType::CombatLock => "combat-lock".to_string(),
+            DoorType::MapRevealer => "map-revealer".to_string(),
         }
-        .to_string()
     }
 
     fn get_spoiler_door_details(
@@ -4852,10 +6899,10 @@ impl<'a> Randomizer<'a> {
             actions: vec![],
         }];
         let door_vertex_info = self.get_vertex_info(door_vertex_id);
+        let door_type = self.locked_door_data.locked_doors[locked_door_idx].door_type;
         SpoilerDoorDetails {
-            door_type: Self::get_door_type_name(
-                self.locked_door_data.locked_doors[locked_door_idx].door_type,
-            ),
+            door_type: Self::get_door_type_name(door_type),
+            weapon: Self::get_door_weapon_name(door_type),
             location: SpoilerLocation {
                 area: door_vertex_info.area_name,
                 room: door_vertex_info.room_name,
@@ -4880,9 +6927,12 @@ impl<'a> Randomizer<'a> {
 
     fn get_spoiler_door_summary(
         &self,
-        _unlock_vertex_id: usize,
+        state: &RandomizationState,
+        unlock_vertex_id: usize,
         locked_door_idx: usize,
     ) -> SpoilerDoorSummary {
+        let (obtain_route, return_route) =
+            self.get_spoiler_route_birectional(state, unlock_vertex_id);
         let locked_door = &self.locked_door_data.locked_doors[locked_door_idx];
         let (room_id, node_id) = self.game_data.door_ptr_pair_map[&locked_door.src_ptr_pair];
         let door_vertex_id = self.game_data.vertex_isv.index_by_key[&VertexKey {
@@ -4892,16 +6942,18 @@ impl<'a> Randomizer<'a> {
             actions: vec![],
         }];
         let door_vertex_info = self.get_vertex_info(door_vertex_id);
+        let door_type = self.locked_door_data.locked_doors[locked_door_idx].door_type;
         SpoilerDoorSummary {
-            door_type: Self::get_door_type_name(
-                self.locked_door_data.locked_doors[locked_door_idx].door_type,
-            ),
+            door_type: Self::get_door_type_name(door_type),
+            weapon: Self::get_door_weapon_name(door_type),
             location: SpoilerLocation {
                 area: door_vertex_info.area_name,
                 room: door_vertex_info.room_name,
                 node: door_vertex_info.node_name,
                 coords: door_vertex_info.room_coords,
             },
+            obtain_route,
+            return_route,
         }
     }
 
@@ -4961,11 +7013,60 @@ impl<'a> Randomizer<'a> {
                 }
             }
         }
+        // These three only include locations that became (one-way or bi-) reachable on this
+        // exact step (tracked via `first_*_step`, stamped once in `update_reachability` and kept
+        // afterward), not every location that happens to still be reachable now -- `bireachable`
+        // is monotonic across steps, so without this a location would reappear in every
+        // subsequent step's summary once first reached.
+        let mut reachable_items: Vec<SpoilerItemSummary> = Vec::new();
+        for i in 0..self.game_data.item_locations.len() {
+            if state.item_location_state[i].first_bireachable_step != Some(state.step_num) {
+                continue;
+            }
+            if let Some(item) = new_state.item_location_state[i].placed_item {
+                let item_vertex_id = state.item_location_state[i].bireachable_vertex_id.unwrap();
+                reachable_items.push(self.get_spoiler_item_summary(state, item_vertex_id, item));
+            }
+        }
+        let mut reachable_flags: Vec<SpoilerFlagSummary> = Vec::new();
+        for (i, &flag_id) in self.game_data.flag_ids.iter().enumerate() {
+            let (vertex_id, first_step) = if flag_id == self.game_data.mother_brain_defeated_flag_id {
+                (
+                    state.flag_location_state[i].reachable_vertex_id,
+                    state.flag_location_state[i].first_reachable_step,
+                )
+            } else {
+                (
+                    state.flag_location_state[i].bireachable_vertex_id,
+                    state.flag_location_state[i].first_bireachable_step,
+                )
+            };
+            if first_step != Some(state.step_num) {
+                continue;
+            }
+            if let Some(vertex_id) = vertex_id {
+                reachable_flags.push(self.get_spoiler_flag_summary(state, vertex_id, flag_id));
+            }
+        }
+        let mut reachable_doors: Vec<SpoilerDoorSummary> = Vec::new();
+        for i in 0..self.locked_door_data.locked_doors.len() {
+            if state.door_state[i].first_bireachable_step != Some(state.step_num) {
+                continue;
+            }
+            if let Some(door_vertex_id) = state.door_state[i].bireachable_vertex_id {
+                reachable_doors.push(self.get_spoiler_door_summary(state, door_vertex_id, i));
+            }
+        }
         SpoilerSummary {
             step: state.step_num,
             items,
             flags: spoiler_flag_summaries,
             doors: spoiler_door_summaries,
+            reachable: SpoilerReachable {
+                items: reachable_items,
+                flags: reachable_flags,
+                doors: reachable_doors,
+            },
         }
     }
 }
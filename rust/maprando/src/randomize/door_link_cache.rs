@@ -0,0 +1,102 @@
+// On-disk memoization of `Preprocessor::get_all_door_links`. The result depends only on the map's
+// door layout, the toilet intersections, and the `DifficultyConfig` fields that feed the
+// cross-room requirement/physics math, so for a given map+difficulty we can skip recomputing it
+// on every generation by keying a cache entry off a content hash of those inputs.
+use crate::randomize::{DifficultyConfig, PhysicsProfile};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use std::path::Path;
+
+// Bump this whenever the cross-room requirement logic (or anything else the cached links depend
+// on) changes, so stale entries from an older build don't get reused.
+const CACHE_VERSION: u32 = 3;
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<L> {
+    version: u32,
+    links: Vec<L>,
+}
+
+pub fn compute_cache_key(
+    door_map_entries: &[((usize, usize), (usize, usize))],
+    toilet_intersections: &[usize],
+    difficulty: &DifficultyConfig,
+) -> String {
+    let mut hasher = Sha3_256::new();
+    hasher.update(CACHE_VERSION.to_le_bytes());
+
+    let mut doors = door_map_entries.to_vec();
+    doors.sort();
+    for ((a, b), (c, d)) in doors {
+        hasher.update((a as u64).to_le_bytes());
+        hasher.update((b as u64).to_le_bytes());
+        hasher.update((c as u64).to_le_bytes());
+        hasher.update((d as u64).to_le_bytes());
+    }
+
+    let mut toilets = toilet_intersections.to_vec();
+    toilets.sort();
+    for t in toilets {
+        hasher.update((t as u64).to_le_bytes());
+    }
+
+    hasher.update(difficulty.shine_charge_tiles.to_le_bytes());
+    hasher.update(difficulty.heated_shine_charge_tiles.to_le_bytes());
+    hasher.update(difficulty.speed_ball_tiles.to_le_bytes());
+    hasher.update(difficulty.shinecharge_leniency_frames.to_le_bytes());
+    hasher.update(difficulty.gate_glitch_leniency.to_le_bytes());
+    hasher.update(difficulty.door_stuck_leniency.to_le_bytes());
+
+    // `get_all_door_links` (via the `get_come_in_*` builders) also consumes the physics profile
+    // and the Monte Carlo repositioning sample count, so both must be part of the key too;
+    // otherwise a seed generated under different physics/sampling settings could reuse links
+    // computed under the old ones.
+    let physics_profile = difficulty.physics_profile.unwrap_or_default();
+    let PhysicsProfile {
+        startup_lenience_frames,
+        reposition_lenience_frames,
+        settle_lenience_frames,
+        min_shinecharge_frames,
+        base_max_run_speed,
+    } = physics_profile;
+    hasher.update(startup_lenience_frames.to_le_bytes());
+    hasher.update(reposition_lenience_frames.to_le_bytes());
+    hasher.update(settle_lenience_frames.to_le_bytes());
+    hasher.update(min_shinecharge_frames.to_le_bytes());
+    hasher.update(base_max_run_speed.to_le_bytes());
+
+    hasher.update([difficulty.monte_carlo_strat_samples.is_some() as u8]);
+    hasher.update((difficulty.monte_carlo_strat_samples.unwrap_or(0) as u64).to_le_bytes());
+
+    // `get_all_door_links`/`add_door_links` also populate `strat_notes` on each link when this is
+    // set, so toggling it between runs on an otherwise-identical map/difficulty must not hit a
+    // cache entry computed under the other value.
+    hasher.update([difficulty
+        .debug_options
+        .as_ref()
+        .is_some_and(|d| d.strat_explanations) as u8]);
+
+    format!("{:x}", hasher.finalize())
+}
+
+pub fn load<L: for<'de> Deserialize<'de>>(cache_dir: &Path, key: &str) -> Option<Vec<L>> {
+    let path = cache_dir.join(format!("{key}.bin"));
+    let bytes = std::fs::read(path).ok()?;
+    let entry: CacheEntry<L> = bincode::deserialize(&bytes).ok()?;
+    if entry.version != CACHE_VERSION {
+        return None;
+    }
+    Some(entry.links)
+}
+
+pub fn store<L: Serialize + Clone>(cache_dir: &Path, key: &str, links: &[L]) -> Result<()> {
+    std::fs::create_dir_all(cache_dir)?;
+    let entry = CacheEntry {
+        version: CACHE_VERSION,
+        links: links.to_vec(),
+    };
+    let bytes = bincode::serialize(&entry)?;
+    std::fs::write(cache_dir.join(format!("{key}.bin")), bytes)?;
+    Ok(())
+}
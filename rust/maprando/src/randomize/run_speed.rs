@@ -0,0 +1,189 @@
+// Frame-accurate run-speed acceleration model, shared by `get_come_in_spinning_reqs`,
+// `get_come_in_blue_spinning_reqs`, and `get_come_in_getting_blue_speed_reqs` (and, via
+// `compute_run_frames`, by the plain running-time checks elsewhere in `randomize.rs`).
+//
+// Samus's speed while running is represented here as "extra run speed": the amount by which her
+// speed exceeds the base (non-accelerated) run speed. It accumulates by a constant acceleration
+// each frame the run button is held, jumping to a steeper "momentum" acceleration once the
+// speed-echo threshold is crossed, and is capped at the game's maximum run speed. Converting
+// between a runway length and the extra run speed it yields (or vice versa) is done by stepping
+// this integrator frame by frame rather than by the flat per-tile formulas used previously, so
+// that `speed_for_runway`/`tiles_for_speed` and the shortcharge bounds all agree with each other
+// at the edges of a strat's speed window.
+
+use crate::game_data::Capacity;
+
+/// Base (non-accelerated) run speed, in tiles/frame. `extra_run_speed` is always measured
+/// relative to this.
+const BASE_RUN_SPEED: f32 = 0.125;
+
+/// Per-frame acceleration below the speed-echo threshold, in tiles/frame^2.
+const RUN_ACCELERATION: f32 = 0.0046875;
+
+/// Per-frame acceleration once the speed-echo threshold is crossed, in tiles/frame^2.
+const MOMENTUM_ACCELERATION: f32 = 0.00703125;
+
+/// Extra run speed (above `BASE_RUN_SPEED`) at which the speed-echo/momentum acceleration kicks
+/// in.
+const SPEED_ECHO_EXTRA_SPEED: f32 = 0.625;
+
+/// Maximum extra run speed attainable (the game's absolute run speed cap, minus
+/// `BASE_RUN_SPEED`).
+const MAX_EXTRA_RUN_SPEED: f32 = 2.0;
+
+/// Number of frames a shinespark shortcharge takes to complete once started.
+const SHORTCHARGE_FRAMES: Capacity = 85;
+
+fn frame_acceleration(extra_speed: f32) -> f32 {
+    if extra_speed < SPEED_ECHO_EXTRA_SPEED {
+        RUN_ACCELERATION
+    } else {
+        MOMENTUM_ACCELERATION
+    }
+}
+
+fn step(extra_speed: f32) -> f32 {
+    f32::min(extra_speed + frame_acceleration(extra_speed), MAX_EXTRA_RUN_SPEED)
+}
+
+/// Distance (in tiles) covered over `frames` frames of running, starting from
+/// `initial_extra_speed`.
+fn distance_after_frames(initial_extra_speed: f32, frames: Capacity) -> f32 {
+    let mut extra_speed = initial_extra_speed;
+    let mut distance = 0.0;
+    for _ in 0..frames {
+        distance += BASE_RUN_SPEED + extra_speed;
+        extra_speed = step(extra_speed);
+    }
+    distance
+}
+
+/// Extra run speed attained after running for `tiles` tiles from a standstill.
+pub fn speed_for_runway(tiles: f32) -> f32 {
+    let mut extra_speed = 0.0;
+    let mut distance = 0.0;
+    while distance < tiles {
+        distance += BASE_RUN_SPEED + extra_speed;
+        extra_speed = step(extra_speed);
+    }
+    extra_speed
+}
+
+/// Runway length (in tiles) needed to reach `extra_run_speed` from a standstill.
+pub fn tiles_for_speed(extra_run_speed: f32) -> f32 {
+    let target = extra_run_speed.clamp(0.0, MAX_EXTRA_RUN_SPEED);
+    let mut extra_speed = 0.0;
+    let mut distance = 0.0;
+    while extra_speed < target {
+        distance += BASE_RUN_SPEED + extra_speed;
+        extra_speed = step(extra_speed);
+    }
+    distance
+}
+
+/// Frames needed to run `tiles` tiles from a standstill.
+pub fn compute_run_frames(tiles: f32) -> Capacity {
+    assert!(tiles >= 0.0);
+    let mut extra_speed = 0.0;
+    let mut distance = 0.0;
+    let mut frames: Capacity = 0;
+    while distance < tiles {
+        distance += BASE_RUN_SPEED + extra_speed;
+        extra_speed = step(extra_speed);
+        frames += 1;
+    }
+    frames
+}
+
+/// Extra run speed and frame count reached after running `tiles` tiles from a standstill.
+pub fn speed_and_frames_for_runway(tiles: f32) -> (f32, Capacity) {
+    let mut extra_speed = 0.0;
+    let mut distance = 0.0;
+    let mut frames: Capacity = 0;
+    while distance < tiles {
+        distance += BASE_RUN_SPEED + extra_speed;
+        extra_speed = step(extra_speed);
+        frames += 1;
+    }
+    (extra_speed, frames)
+}
+
+/// Frames to run out `tiles` tiles from the door and then immediately run back to it, e.g. for
+/// `from_exit_node` runways where the door must be re-entered before reaching the destination.
+/// Both legs start from a standstill, so this is exactly twice the one-way frame count.
+pub fn round_trip_run_frames(tiles: f32) -> Capacity {
+    compute_run_frames(tiles) * 2
+}
+
+pub fn get_max_extra_run_speed(remote_runway_length: f32) -> f32 {
+    speed_for_runway(remote_runway_length)
+}
+
+pub fn get_extra_run_speed_tiles(extra_run_speed: f32) -> f32 {
+    tiles_for_speed(extra_run_speed)
+}
+
+/// Minimum extra run speed with which a shortcharge runway of `tiles` tiles can still accumulate
+/// a full shinecharge before running out of room.
+pub fn get_shortcharge_min_extra_run_speed(tiles: f32) -> f32 {
+    let mut lo = 0.0_f32;
+    let mut hi = MAX_EXTRA_RUN_SPEED;
+    for _ in 0..30 {
+        let mid = (lo + hi) / 2.0;
+        if distance_after_frames(mid, SHORTCHARGE_FRAMES) >= tiles {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+/// Searches evenly spaced stopping points within `[min_tiles, max_tiles]` of a runway for the
+/// cheapest total frame cost of running to that point, repositioning (`reposition_frames`) to
+/// turn around and resume, then running the remaining `total_tiles - stopping_point`. This is
+/// for strats where the player can choose how much of an available runway to use rather than
+/// being forced to use all of it, bounded by a configurable `samples` budget so this stays cheap
+/// to evaluate at generation time. `max_tiles` is always included as a candidate, so enabling
+/// this can only match or improve on the previous worst-case assumption of stopping exactly at
+/// `max_tiles`, never make it worse.
+pub fn min_cost_reposition_frames(
+    total_tiles: f32,
+    min_tiles: f32,
+    max_tiles: f32,
+    reposition_frames: Capacity,
+    samples: usize,
+) -> Capacity {
+    let samples = samples.max(1);
+    let mut best = Capacity::MAX;
+    for i in 0..samples {
+        let t = if samples == 1 {
+            max_tiles
+        } else {
+            min_tiles + (max_tiles - min_tiles) * i as f32 / (samples - 1) as f32
+        };
+        let cost = compute_run_frames(total_tiles - t) + compute_run_frames(t) + reposition_frames;
+        best = Capacity::min(best, cost);
+    }
+    best
+}
+
+/// Maximum extra run speed with which a shortcharge runway of `tiles` minimum tiles, actually
+/// `runway_length` tiles long, can still finish charging without running past the end of the
+/// runway. Returns `None` if `runway_length` is too short to shortcharge at all.
+pub fn get_shortcharge_max_extra_run_speed(tiles: f32, runway_length: f32) -> Option<f32> {
+    if runway_length < tiles {
+        return None;
+    }
+    let mut lo = 0.0_f32;
+    let mut hi = MAX_EXTRA_RUN_SPEED;
+    for _ in 0..30 {
+        let mid = (lo + hi) / 2.0;
+        if distance_after_frames(mid, SHORTCHARGE_FRAMES) <= runway_length {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(lo)
+}
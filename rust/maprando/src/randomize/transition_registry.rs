@@ -0,0 +1,239 @@
+// Declarative registry describing, for each entrance strat's `get_come_in_*` builder, which
+// `ExitCondition` variants it accepts. This exists so the entrance/exit compatibility table is
+// visible in one place instead of only being implicit in each builder's own `match` arms, and so
+// a completeness check can catch an `ExitCondition` variant that no builder claims to handle
+// (which would otherwise silently show up only as a door pair being unreachable in-game).
+//
+// This is intentionally just a table of names, not a full closure-based handler registry: the
+// builders themselves still do the real matching, since they also need the entrance-condition
+// geometry (runway lengths, speed windows, etc.) that a bare `(name, exit_variant)` pair doesn't
+// carry. Fully replacing the match-based dispatch in `get_cross_room_reqs` with registered
+// handler objects is a larger refactor than is safe to do without a compiler in the loop; this
+// table is the groundwork for it, and gives the cross-product a single place to audit.
+
+use crate::game_data::ExitCondition;
+
+pub struct EntranceHandler {
+    pub builder_name: &'static str,
+    pub accepted_exit_conditions: &'static [&'static str],
+}
+
+pub const ENTRANCE_HANDLERS: &[EntranceHandler] = &[
+    EntranceHandler {
+        builder_name: "get_come_in_normally_reqs",
+        accepted_exit_conditions: &["LeaveNormally"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_running_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_space_jumping_reqs",
+        accepted_exit_conditions: &["LeaveSpaceJumping"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_getting_blue_speed_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_shinecharging_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_speedballing_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_spinning_reqs",
+        accepted_exit_conditions: &["LeaveSpinning", "LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_blue_spinning_reqs",
+        accepted_exit_conditions: &["LeaveSpinning", "LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_mockball_reqs",
+        accepted_exit_conditions: &["LeaveWithMockball", "LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_spring_ball_bounce_reqs",
+        accepted_exit_conditions: &[
+            "LeaveWithMockball",
+            "LeaveWithRunway",
+            "LeaveWithSpringBallBounce",
+        ],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_blue_spring_ball_bounce_reqs",
+        accepted_exit_conditions: &[
+            "LeaveWithMockball",
+            "LeaveWithRunway",
+            "LeaveWithSpringBallBounce",
+        ],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_shinecharged_reqs",
+        accepted_exit_conditions: &["LeaveShinecharged", "LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_shinecharged_jumping_reqs",
+        accepted_exit_conditions: &["LeaveShinecharged", "LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_stutter_shinecharging_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_spark_reqs",
+        accepted_exit_conditions: &["LeaveShinecharged", "LeaveWithRunway", "LeaveWithSpark"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_temporary_blue_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway", "LeaveWithTemporaryBlue"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_bomb_boost_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_door_stuck_setup_reqs",
+        accepted_exit_conditions: &["LeaveWithRunway"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_r_mode_reqs",
+        accepted_exit_conditions: &["LeaveWithGModeSetup"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_g_mode_reqs",
+        accepted_exit_conditions: &["LeaveWithGMode", "LeaveWithGModeSetup"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_stored_fall_speed_reqs",
+        accepted_exit_conditions: &["LeaveWithStoredFallSpeed"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_wall_jump_below_reqs",
+        accepted_exit_conditions: &["LeaveWithDoorFrameBelow"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_space_jump_below_reqs",
+        accepted_exit_conditions: &["LeaveWithDoorFrameBelow"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_grapple_teleport_reqs",
+        accepted_exit_conditions: &["LeaveWithGrappleTeleport"],
+    },
+    EntranceHandler {
+        builder_name: "get_come_in_with_platform_below_reqs",
+        accepted_exit_conditions: &["LeaveWithPlatformBelow"],
+    },
+];
+
+/// Name-only mirror of `ExitCondition`'s variants, used solely to drive `exit_condition_tag`'s
+/// exhaustiveness check below. Previously this registry's completeness check compared
+/// `ALL_EXIT_CONDITIONS` (a hand-copied string list) against `ENTRANCE_HANDLERS` (also
+/// hand-maintained) -- two lists that could drift from each other and from the real enum at the
+/// same time, with nothing catching it. `exit_condition_tag` matches on the actual
+/// `ExitCondition` with no wildcard arm, so the compiler itself rejects a new real variant that
+/// isn't added to this mirror. `ALL_EXIT_CONDITION_TAGS` below is still a hand-written list of
+/// this mirror's variants; a tag left out of it is not caught by the compiler.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExitConditionTag {
+    LeaveNormally,
+    LeaveShinecharged,
+    LeaveSpaceJumping,
+    LeaveSpinning,
+    LeaveWithDoorFrameBelow,
+    LeaveWithGMode,
+    LeaveWithGModeSetup,
+    LeaveWithGrappleTeleport,
+    LeaveWithMockball,
+    LeaveWithPlatformBelow,
+    LeaveWithRunway,
+    LeaveWithSpark,
+    LeaveWithSpringBallBounce,
+    LeaveWithStoredFallSpeed,
+    LeaveWithTemporaryBlue,
+}
+
+pub const ALL_EXIT_CONDITION_TAGS: &[ExitConditionTag] = &[
+    ExitConditionTag::LeaveNormally,
+    ExitConditionTag::LeaveShinecharged,
+    ExitConditionTag::LeaveSpaceJumping,
+    ExitConditionTag::LeaveSpinning,
+    ExitConditionTag::LeaveWithDoorFrameBelow,
+    ExitConditionTag::LeaveWithGMode,
+    ExitConditionTag::LeaveWithGModeSetup,
+    ExitConditionTag::LeaveWithGrappleTeleport,
+    ExitConditionTag::LeaveWithMockball,
+    ExitConditionTag::LeaveWithPlatformBelow,
+    ExitConditionTag::LeaveWithRunway,
+    ExitConditionTag::LeaveWithSpark,
+    ExitConditionTag::LeaveWithSpringBallBounce,
+    ExitConditionTag::LeaveWithStoredFallSpeed,
+    ExitConditionTag::LeaveWithTemporaryBlue,
+];
+
+impl ExitConditionTag {
+    pub fn name(self) -> &'static str {
+        match self {
+            ExitConditionTag::LeaveNormally => "LeaveNormally",
+            ExitConditionTag::LeaveShinecharged => "LeaveShinecharged",
+            ExitConditionTag::LeaveSpaceJumping => "LeaveSpaceJumping",
+            ExitConditionTag::LeaveSpinning => "LeaveSpinning",
+            ExitConditionTag::LeaveWithDoorFrameBelow => "LeaveWithDoorFrameBelow",
+            ExitConditionTag::LeaveWithGMode => "LeaveWithGMode",
+            ExitConditionTag::LeaveWithGModeSetup => "LeaveWithGModeSetup",
+            ExitConditionTag::LeaveWithGrappleTeleport => "LeaveWithGrappleTeleport",
+            ExitConditionTag::LeaveWithMockball => "LeaveWithMockball",
+            ExitConditionTag::LeaveWithPlatformBelow => "LeaveWithPlatformBelow",
+            ExitConditionTag::LeaveWithRunway => "LeaveWithRunway",
+            ExitConditionTag::LeaveWithSpark => "LeaveWithSpark",
+            ExitConditionTag::LeaveWithSpringBallBounce => "LeaveWithSpringBallBounce",
+            ExitConditionTag::LeaveWithStoredFallSpeed => "LeaveWithStoredFallSpeed",
+            ExitConditionTag::LeaveWithTemporaryBlue => "LeaveWithTemporaryBlue",
+        }
+    }
+}
+
+/// Classifies `exit_condition` by variant. Exhaustive over the real `ExitCondition` enum (no
+/// wildcard arm), so adding a new variant there without adding a corresponding arm here is a
+/// compile error rather than a silently-passing completeness check.
+pub fn exit_condition_tag(exit_condition: &ExitCondition) -> ExitConditionTag {
+    match exit_condition {
+        ExitCondition::LeaveNormally { .. } => ExitConditionTag::LeaveNormally,
+        ExitCondition::LeaveShinecharged { .. } => ExitConditionTag::LeaveShinecharged,
+        ExitCondition::LeaveSpaceJumping { .. } => ExitConditionTag::LeaveSpaceJumping,
+        ExitCondition::LeaveSpinning { .. } => ExitConditionTag::LeaveSpinning,
+        ExitCondition::LeaveWithDoorFrameBelow { .. } => ExitConditionTag::LeaveWithDoorFrameBelow,
+        ExitCondition::LeaveWithGMode { .. } => ExitConditionTag::LeaveWithGMode,
+        ExitCondition::LeaveWithGModeSetup { .. } => ExitConditionTag::LeaveWithGModeSetup,
+        ExitCondition::LeaveWithGrappleTeleport { .. } => {
+            ExitConditionTag::LeaveWithGrappleTeleport
+        }
+        ExitCondition::LeaveWithMockball { .. } => ExitConditionTag::LeaveWithMockball,
+        ExitCondition::LeaveWithPlatformBelow { .. } => ExitConditionTag::LeaveWithPlatformBelow,
+        ExitCondition::LeaveWithRunway { .. } => ExitConditionTag::LeaveWithRunway,
+        ExitCondition::LeaveWithSpark { .. } => ExitConditionTag::LeaveWithSpark,
+        ExitCondition::LeaveWithSpringBallBounce { .. } => {
+            ExitConditionTag::LeaveWithSpringBallBounce
+        }
+        ExitCondition::LeaveWithStoredFallSpeed { .. } => {
+            ExitConditionTag::LeaveWithStoredFallSpeed
+        }
+        ExitCondition::LeaveWithTemporaryBlue { .. } => ExitConditionTag::LeaveWithTemporaryBlue,
+    }
+}
+
+/// `ExitCondition` variants (by tag) that no registered handler accepts.
+pub fn unclaimed_exit_conditions() -> Vec<&'static str> {
+    ALL_EXIT_CONDITION_TAGS
+        .iter()
+        .map(|tag| tag.name())
+        .filter(|name| {
+            !ENTRANCE_HANDLERS
+                .iter()
+                .any(|handler| handler.accepted_exit_conditions.contains(name))
+        })
+        .collect()
+}
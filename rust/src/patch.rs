@@ -2,19 +2,109 @@ use std::path::Path;
 
 use crate::{
     game_data::{GameData, Item, Map, NodePtr},
-    randomize::Randomization,
+    randomize::{DifficultyConfig, Randomization},
 };
 use anyhow::{ensure, Context, Result};
 use hashbrown::HashMap;
 use ips;
 use std::iter;
 
-const NUM_AREAS: usize = 6;
+// Declarative table of map areas, each with its minimap tilemap base pointer (where `write_map_tilemaps`
+// writes that area's 64x32 tile grid) and its map palette/tint index (recorded only; see `palette`).
+// This replaces the previous hardcoded `NUM_AREAS = 6` constant and parallel `area_map_ptrs` list,
+// so adding, splitting, or merging areas (e.g. giving bosses their own area) is a matter of editing
+// this table rather than touching `write_map_tilemaps`/`write_map_areas` themselves. The actual
+// room->area assignment is still `self.map.area`, built upstream by the randomizer; this table only
+// governs how each resolved area index is rendered.
+pub struct AreaDef {
+    pub name: &'static str,
+    pub tilemap_base_ptr: isize,
+    // Map palette/tint index for this area. Recorded here so the table is a complete per-area
+    // definition, but neither `write_map_tilemaps` nor `write_map_areas` emitted any palette data
+    // before this change either (the `map_area` IPS patch hardcodes the vanilla six tints), so
+    // this isn't wired to a ROM write yet.
+    pub palette: isize,
+}
+
+pub const AREA_DEFS: &[AreaDef] = &[
+    AreaDef {
+        name: "Crateria",
+        tilemap_base_ptr: 0x1A9000,
+        palette: 0,
+    },
+    AreaDef {
+        name: "Brinstar",
+        tilemap_base_ptr: 0x1A8000,
+        palette: 1,
+    },
+    AreaDef {
+        name: "Norfair",
+        tilemap_base_ptr: 0x1AA000,
+        palette: 2,
+    },
+    AreaDef {
+        name: "Wrecked Ship",
+        tilemap_base_ptr: 0x1AB000,
+        palette: 3,
+    },
+    AreaDef {
+        name: "Maridia",
+        tilemap_base_ptr: 0x1AC000,
+        palette: 4,
+    },
+    AreaDef {
+        name: "Tourian",
+        tilemap_base_ptr: 0x1AD000,
+        palette: 5,
+    },
+];
+
+// Rooms that share their minimap tile data with another room's area ("twin rooms": a second
+// entrance into the same physical space that the vanilla game files under a different room index).
+// `orig_area_idx` and `room_index` identify the twin room's slot in the vanilla per-area room-index
+// table (the same addressing `write_map_areas` reads out of the original ROM); `source_room_name`
+// is the room whose (possibly randomized) area it should be forced to match.
+pub struct TwinRoomOverride {
+    pub orig_area_idx: usize,
+    pub room_index: usize,
+    pub source_room_name: &'static str,
+}
+
+pub const TWIN_ROOM_OVERRIDES: &[TwinRoomOverride] = &[
+    TwinRoomOverride {
+        orig_area_idx: 4,
+        room_index: 0x18,
+        source_room_name: "Aqueduct",
+    }, // Toilet
+    TwinRoomOverride {
+        orig_area_idx: 4,
+        room_index: 0x25,
+        source_room_name: "Pants Room",
+    }, // East Pants Room
+    TwinRoomOverride {
+        orig_area_idx: 0,
+        room_index: 0x11,
+        source_room_name: "West Ocean",
+    }, // Homing Geemer Room
+];
 
 fn snes2pc(addr: usize) -> usize {
     addr >> 1 & 0x3F8000 | addr & 0x7FFF
 }
 
+// Sentinel tilemap word meaning "leave this cell alone" in a `RoomMapPrefab`, so a prefab can
+// cover only part of a room's map footprint (e.g. the non-rectangular parts) without having to
+// spell out every cell.
+pub const PREFAB_BLANK: u16 = 0xFFFF;
+
+// A hand-authored minimap tile template for a room that has no sensible "copy from the original
+// ROM" source, e.g. a room the randomizer constructs or substantially reshapes. `rows` is indexed
+// `[y][x]` and must match the room's `room_geometry` map dimensions exactly; `write_map_tilemaps`
+// overlays it onto the room's computed map position instead of copying tiles out of `orig_rom`.
+pub struct RoomMapPrefab {
+    pub rows: Vec<Vec<u16>>,
+}
+
 #[derive(Clone)]
 pub struct Rom {
     pub data: Vec<u8>,
@@ -107,12 +197,52 @@ impl Rom {
     }
 }
 
+// Records which patching step last wrote each ROM byte, so a later step overwriting a byte an
+// earlier step already touched shows up as a collision instead of silently disappearing. Patch
+// application order matters (see `PATCH_MANIFEST`/`apply_ips_patches`), so this exists to catch
+// an accidental reordering or an overlapping address range before it ships as a silent regression.
+#[derive(Clone, Debug)]
+pub struct WriteCollision {
+    pub address: usize,
+    pub first_source: String,
+    pub second_source: String,
+    // False when the second write happened to re-assert the same byte value as the first (usually
+    // harmless); true when it actually replaced the byte with something different.
+    pub value_changed: bool,
+}
+
+#[derive(Default)]
+pub struct WriteJournal {
+    owners: HashMap<usize, String>,
+    pub collisions: Vec<WriteCollision>,
+}
+
+impl WriteJournal {
+    fn note_write(&mut self, source: &str, addr: usize, before: &[u8], after: &[u8]) {
+        for (i, (&b, &a)) in before.iter().zip(after.iter()).enumerate() {
+            let address = addr + i;
+            if let Some(existing_source) = self.owners.get(&address) {
+                if existing_source != source {
+                    self.collisions.push(WriteCollision {
+                        address,
+                        first_source: existing_source.clone(),
+                        second_source: source.to_string(),
+                        value_changed: b != a,
+                    });
+                }
+            }
+            self.owners.insert(address, source.to_string());
+        }
+    }
+}
+
 pub struct Patcher<'a> {
     pub orig_rom: &'a mut Rom,
     pub rom: &'a mut Rom,
     pub randomization: &'a Randomization,
     pub game_data: &'a GameData,
     pub map: &'a Map,
+    pub write_journal: WriteJournal,
 }
 
 fn xy_to_map_offset(x: isize, y: isize) -> isize {
@@ -130,57 +260,118 @@ fn item_to_plm_type(item: Item, orig_plm_type: isize) -> isize {
     orig_plm_type + (item_id - old_item_id) * 4
 }
 
+// Declarative manifest of optional IPS patches: each entry names a patch file (without the
+// `.ips` extension) and a predicate over the active `DifficultyConfig` deciding whether it should
+// be applied to this seed. Entries are applied in the order listed here, which is also the order
+// later patches are allowed to depend on/overwrite earlier ones. This exists so the patch set can
+// vary by settings (debug build, music, sound effects, map-area style, fast reload) without
+// editing `apply_ips_patches` itself, and so the resolved set is introspectable/loggable instead
+// of being implicit in a hardcoded list.
+struct PatchManifestEntry {
+    name: &'static str,
+    enabled: fn(&DifficultyConfig) -> bool,
+}
+
+const PATCH_MANIFEST: &[PatchManifestEntry] = &[
+    PatchManifestEntry { name: "mb_barrier", enabled: |_| true },
+    PatchManifestEntry { name: "mb_barrier_clear", enabled: |_| true },
+    PatchManifestEntry { name: "hud_expansion_opaque", enabled: |_| true },
+    PatchManifestEntry { name: "gray_doors", enabled: |_| true },
+    PatchManifestEntry { name: "vanilla_bugfixes", enabled: |_| true },
+    PatchManifestEntry { name: "music", enabled: |d| d.music_enabled },
+    PatchManifestEntry { name: "crateria_sky_fixed", enabled: |_| true },
+    PatchManifestEntry { name: "everest_tube", enabled: |_| true },
+    PatchManifestEntry { name: "sandfalls", enabled: |_| true },
+    PatchManifestEntry { name: "saveload", enabled: |_| true },
+    // Only needed when rooms have actually been reassigned to different map areas.
+    PatchManifestEntry { name: "map_area", enabled: |d| !d.vanilla_map },
+    PatchManifestEntry { name: "elevators_speed", enabled: |_| true },
+    PatchManifestEntry { name: "boss_exit", enabled: |_| true },
+    PatchManifestEntry { name: "itemsounds", enabled: |_| true },
+    PatchManifestEntry { name: "progressive_suits", enabled: |_| true },
+    PatchManifestEntry { name: "disable_map_icons", enabled: |_| true },
+    PatchManifestEntry { name: "escape", enabled: |_| true },
+    PatchManifestEntry { name: "mother_brain_no_drain", enabled: |_| true },
+    PatchManifestEntry { name: "tourian_map", enabled: |_| true },
+    PatchManifestEntry { name: "tourian_eye_door", enabled: |_| true },
+    PatchManifestEntry { name: "no_explosions_before_escape", enabled: |_| true },
+    PatchManifestEntry { name: "escape_room_1", enabled: |_| true },
+    PatchManifestEntry { name: "unexplore", enabled: |_| true },
+    PatchManifestEntry { name: "max_ammo_display", enabled: |_| true },
+    PatchManifestEntry { name: "missile_refill_all", enabled: |_| true },
+    PatchManifestEntry { name: "sound_effect_disables", enabled: |d| !d.sound_effects_enabled },
+    PatchManifestEntry { name: "title_map_animation", enabled: |_| true },
+    PatchManifestEntry { name: "fast_reload", enabled: |d| d.fast_reload },
+    // Debug builds get the extra new-game state dump instead of the normal new-game patch.
+    PatchManifestEntry { name: "new_game_extra", enabled: |d| d.debug_options.is_some() },
+    PatchManifestEntry { name: "new_game", enabled: |d| d.debug_options.is_none() },
+];
+
 impl<'a> Patcher<'a> {
-    fn apply_ips_patch(&mut self, patch_path: &Path) -> Result<()> {
+    // Write helpers that route through `self.rom.write_*` as before, but additionally tell
+    // `write_journal` which step owns each byte so overlapping writes across patches/steps can be
+    // detected. `source` should identify the calling step (e.g. `"patch:music"`, `"place_items"`).
+    fn journaled_write_u8(&mut self, source: &str, addr: usize, x: isize) -> Result<()> {
+        let before = self.rom.read_n(addr, 1)?.to_vec();
+        self.rom.write_u8(addr, x)?;
+        let after = self.rom.read_n(addr, 1)?.to_vec();
+        self.write_journal.note_write(source, addr, &before, &after);
+        Ok(())
+    }
+
+    fn journaled_write_u16(&mut self, source: &str, addr: usize, x: isize) -> Result<()> {
+        let before = self.rom.read_n(addr, 2)?.to_vec();
+        self.rom.write_u16(addr, x)?;
+        let after = self.rom.read_n(addr, 2)?.to_vec();
+        self.write_journal.note_write(source, addr, &before, &after);
+        Ok(())
+    }
+
+    fn journaled_write_n(&mut self, source: &str, addr: usize, data: &[u8]) -> Result<()> {
+        let before = self.rom.read_n(addr, data.len())?.to_vec();
+        self.rom.write_n(addr, data)?;
+        let after = self.rom.read_n(addr, data.len())?.to_vec();
+        self.write_journal.note_write(source, addr, &before, &after);
+        Ok(())
+    }
+
+    fn apply_ips_patch(&mut self, patch_name: &str, patch_path: &Path) -> Result<()> {
         let patch_data = std::fs::read(&patch_path)
             .with_context(|| format!("Unable to read patch {}", patch_path.display()))?;
         let patch = ips::Patch::parse(&patch_data)
             .with_context(|| format!("Unable to parse patch {}", patch_path.display()))?;
+        let source = format!("patch:{patch_name}");
         for hunk in patch.hunks() {
-            self.rom.write_n(hunk.offset(), hunk.payload())?;
+            self.journaled_write_n(&source, hunk.offset(), hunk.payload())?;
         }
         Ok(())
     }
 
-    fn apply_ips_patches(&mut self) -> Result<()> {
+    // Resolves the manifest down to the patch names that apply to the current `DifficultyConfig`,
+    // preserving manifest order.
+    fn resolve_patch_names(&self) -> Vec<&'static str> {
+        PATCH_MANIFEST
+            .iter()
+            .filter(|entry| (entry.enabled)(&self.randomization.difficulty))
+            .map(|entry| entry.name)
+            .collect()
+    }
+
+    // Applies every patch the manifest selects for this seed's settings, in declared order,
+    // returning the resolved name list so callers can log/introspect which patches were applied.
+    fn apply_ips_patches(&mut self) -> Result<Vec<&'static str>> {
         let patches_dir = Path::new("../patches/ips/");
-        let mut patches = vec![
-            "mb_barrier",
-            "mb_barrier_clear",
-            "hud_expansion_opaque",
-            "gray_doors",
-            "vanilla_bugfixes",
-            "music",
-            "crateria_sky_fixed",
-            "everest_tube",
-            "sandfalls",
-            "saveload",
-            "map_area",
-            "elevators_speed",
-            "boss_exit",
-            "itemsounds",
-            "progressive_suits",
-            "disable_map_icons",
-            "escape",
-            "mother_brain_no_drain",
-            "tourian_map",
-            "tourian_eye_door",
-            "no_explosions_before_escape",
-            "escape_room_1",
-            "unexplore",
-            "max_ammo_display",
-            "missile_refill_all",
-            "sound_effect_disables",
-            "title_map_animation",
-            "fast_reload",
-        ];
-        patches.push("new_game_extra");
-        // "new_game_extra' if args.debug else 'new_game",
-        for patch_name in patches {
+        let patch_names = self.resolve_patch_names();
+        for &patch_name in &patch_names {
             let patch_path = patches_dir.join(patch_name.to_string() + ".ips");
-            self.apply_ips_patch(&patch_path)?;
+            ensure!(
+                patch_path.is_file(),
+                "Patch '{patch_name}' is declared in the manifest but missing at {}",
+                patch_path.display()
+            );
+            self.apply_ips_patch(patch_name, &patch_path)?;
         }
-        Ok(())
+        Ok(patch_names)
     }
 
     fn place_items(&mut self) -> Result<()> {
@@ -191,7 +382,7 @@ impl<'a> Patcher<'a> {
             let item_plm_ptr = self.game_data.node_ptr_map[&loc];
             let orig_plm_type = self.orig_rom.read_u16(item_plm_ptr)?;
             let new_plm_type = item_to_plm_type(item, orig_plm_type);
-            self.rom.write_u16(item_plm_ptr, new_plm_type)?;
+            self.journaled_write_u16("place_items", item_plm_ptr, new_plm_type)?;
         }
         Ok(())
     }
@@ -204,12 +395,12 @@ impl<'a> Patcher<'a> {
         dst_entrance_ptr: Option<usize>,
     ) -> Result<()> {
         if src_exit_ptr.is_some() && dst_entrance_ptr.is_some() {
-            let door_data = self.orig_rom.read_n(dst_entrance_ptr.unwrap(), 12)?;
-            self.rom.write_n(src_exit_ptr.unwrap(), door_data)?;
+            let door_data = self.orig_rom.read_n(dst_entrance_ptr.unwrap(), 12)?.to_vec();
+            self.journaled_write_n("connect_doors", src_exit_ptr.unwrap(), &door_data)?;
         }
         if dst_exit_ptr.is_some() && src_entrance_ptr.is_some() {
-            let door_data = self.orig_rom.read_n(src_entrance_ptr.unwrap(), 12)?;
-            self.rom.write_n(dst_exit_ptr.unwrap(), door_data)?;
+            let door_data = self.orig_rom.read_n(src_entrance_ptr.unwrap(), 12)?.to_vec();
+            self.journaled_write_n("connect_doors", dst_exit_ptr.unwrap(), &door_data)?;
         }
         Ok(())
     }
@@ -255,25 +446,35 @@ impl<'a> Patcher<'a> {
             let orig_entrance_door_ptr = (self.orig_rom.read_u16(ptr + 2)? + 0x10000) as NodePtr;
             let exit_door_ptr = orig_door_map[&orig_entrance_door_ptr];
             let entrance_door_ptr = new_door_map[&exit_door_ptr];
-            self.rom
-                .write_u16(ptr + 2, (entrance_door_ptr & 0xFFFF) as isize)?;
+            self.journaled_write_u16(
+                "fix_save_stations",
+                ptr + 2,
+                (entrance_door_ptr & 0xFFFF) as isize,
+            )?;
         }
         Ok(())
     }
 
-    fn write_map_tilemaps(&mut self) -> Result<()> {
-        let area_map_ptrs: Vec<isize> = vec![
-            0x1A9000,  // Crateria
-            0x1A8000,  // Brinstar
-            0x1AA000,  // Norfair
-            0x1AB000,  // Wrecked ship
-            0x1AC000,  // Maridia
-            0x1AD000,  // Tourian
-        ];
+    fn write_map_tilemaps(
+        &mut self,
+        room_map_prefabs: &HashMap<String, RoomMapPrefab>,
+    ) -> Result<()> {
+        let area_map_ptrs: Vec<isize> = AREA_DEFS.iter().map(|a| a.tilemap_base_ptr).collect();
+
+        // Resolve prefab room names to room indices once, up front.
+        let mut prefab_by_room_idx: HashMap<usize, &RoomMapPrefab> = HashMap::new();
+        for (room_name, prefab) in room_map_prefabs {
+            let room_idx = *self
+                .game_data
+                .room_idx_by_name
+                .get(room_name.as_str())
+                .with_context(|| format!("Unknown room '{room_name}' in map prefab set"))?;
+            prefab_by_room_idx.insert(room_idx, prefab);
+        }
 
         // Determine upper-left corner of each area:
-        let mut area_map_min_x = [isize::MAX; NUM_AREAS];
-        let mut area_map_min_y = [isize::MAX; NUM_AREAS];
+        let mut area_map_min_x = vec![isize::MAX; AREA_DEFS.len()];
+        let mut area_map_min_y = vec![isize::MAX; AREA_DEFS.len()];
         for i in 0..self.map.area.len() {
             let area = self.map.area[i];
             let x = self.map.rooms[i].0 as isize;
@@ -289,7 +490,11 @@ impl<'a> Patcher<'a> {
         // Clear all map tilemap data:
         for area_ptr in &area_map_ptrs {
             for i in 0..(64 * 32) {
-                self.rom.write_u16((area_ptr + i * 2) as usize, 0x001F)?;
+                self.journaled_write_u16(
+                    "write_map_tilemaps",
+                    (area_ptr + i * 2) as usize,
+                    0x001F,
+                )?;
             }
         }
 
@@ -303,8 +508,44 @@ impl<'a> Patcher<'a> {
             let new_base_ptr = area_map_ptrs[new_area];
             let new_base_x = self.map.rooms[i].0 as isize - area_map_min_x[new_area] + 2;
             let new_base_y = self.map.rooms[i].1 as isize - area_map_min_y[new_area] + 1;
-            self.rom.write_u8(room.rom_address + 2, new_base_x)?;
-            self.rom.write_u8(room.rom_address + 3, new_base_y)?;
+            self.journaled_write_u8("write_map_tilemaps", room.rom_address + 2, new_base_x)?;
+            self.journaled_write_u8("write_map_tilemaps", room.rom_address + 3, new_base_y)?;
+
+            if let Some(&prefab) = prefab_by_room_idx.get(&i) {
+                ensure!(
+                    prefab.rows.len() == room.map.len()
+                        && prefab.rows.iter().all(|row| row.len() == room.map[0].len()),
+                    "Map prefab for room index {i} has dimensions {}x{}, but room_geometry expects {}x{}",
+                    prefab.rows.first().map_or(0, |r| r.len()),
+                    prefab.rows.len(),
+                    room.map[0].len(),
+                    room.map.len(),
+                );
+                for y in 0..room.map.len() {
+                    for x in 0..room.map[0].len() {
+                        let word = prefab.rows[y][x];
+                        if word == PREFAB_BLANK {
+                            continue;
+                        }
+                        let new_x = new_base_x + x as isize;
+                        let new_y = new_base_y + y as isize;
+                        ensure!(
+                            (0..64).contains(&new_x),
+                            "Map prefab for room index {i} places a tile at map x={new_x}, outside the valid 0..64 range"
+                        );
+                        let new_offset = xy_to_map_offset(new_x, new_y);
+                        let word_idx = (new_offset / 2) as usize;
+                        ensure!(
+                            word_idx < 64 * 32,
+                            "Map prefab for room index {i} places a tile at map position ({new_x}, {new_y}), past the 64x32 area tilemap"
+                        );
+                        let new_ptr = (new_base_ptr + new_offset) as usize;
+                        self.journaled_write_u16("write_map_tilemaps", new_ptr, word as isize)?;
+                    }
+                }
+                continue;
+            }
+
             for y in 0..room.map.len() {
                 for x in 0..room.map[0].len() {
                     if room.map[y][x] == 0 {
@@ -319,7 +560,7 @@ impl<'a> Patcher<'a> {
                     let new_offset = xy_to_map_offset(new_x, new_y);
                     let new_ptr = (new_base_ptr + new_offset) as usize;
                     let data = self.orig_rom.read_u16(orig_ptr)?;
-                    self.rom.write_u16(new_ptr, data)?;
+                    self.journaled_write_u16("write_map_tilemaps", new_ptr, data)?;
                 }
             }
         }
@@ -328,7 +569,7 @@ impl<'a> Patcher<'a> {
 
     fn write_map_areas(&mut self) -> Result<()> {
         let mut room_index_area_hashmaps: Vec<HashMap<usize, usize>> =
-            vec![HashMap::new(); NUM_AREAS];
+            vec![HashMap::new(); AREA_DEFS.len()];
         for (i, room) in self.game_data.room_geometry.iter().enumerate() {
             let room_index = self.orig_rom.read_u8(room.rom_address)? as usize;
             let orig_room_area = self.orig_rom.read_u8(room.rom_address + 1)? as usize;
@@ -337,28 +578,33 @@ impl<'a> Patcher<'a> {
             room_index_area_hashmaps[orig_room_area].insert(room_index, new_area);
         }
 
-        // Handle twin rooms:
-        let aqueduct_room_idx = self.game_data.room_idx_by_name["Aqueduct"];
-        room_index_area_hashmaps[4].insert(0x18, self.map.area[aqueduct_room_idx]); // Set Toilet to same map area as Aqueduct
-        let pants_room_idx = self.game_data.room_idx_by_name["Pants Room"];
-        room_index_area_hashmaps[4].insert(0x25, self.map.area[pants_room_idx]); // Set East Pants Room to same area as Pants Room
-        let west_ocean_room_idx = self.game_data.room_idx_by_name["West Ocean"];
-        room_index_area_hashmaps[0].insert(0x11, self.map.area[west_ocean_room_idx]); // Set Homing Geemer Room to same area as West Ocean
+        // Handle twin rooms, per the declarative `TWIN_ROOM_OVERRIDES` table:
+        for twin in TWIN_ROOM_OVERRIDES {
+            let source_room_idx = self.game_data.room_idx_by_name[twin.source_room_name];
+            room_index_area_hashmaps[twin.orig_area_idx]
+                .insert(twin.room_index, self.map.area[source_room_idx]);
+        }
 
         // Write the information about each room's map area to some free space in bank 0x8F
         // which will be read by the `map_area` patch.
         let area_data_base_ptr = snes2pc(0x8FE99B);
-        let mut area_data_ptr_pc = area_data_base_ptr + 2 * NUM_AREAS;
-        for area in 0..NUM_AREAS {
+        let mut area_data_ptr_pc = area_data_base_ptr + 2 * AREA_DEFS.len();
+        for area in 0..AREA_DEFS.len() {
             // Write pointer to the start of the table for the given area:
             let area_data_ptr_snes = (area_data_ptr_pc & 0x7FFF) | 0x8000;
-            self.rom
-                .write_u16(area_data_base_ptr + 2 * area, area_data_ptr_snes as isize)?;
+            self.journaled_write_u16(
+                "write_map_areas",
+                area_data_base_ptr + 2 * area,
+                area_data_ptr_snes as isize,
+            )?;
 
             // Write the table contents:
             for (&room_index, &new_area) in &room_index_area_hashmaps[area] {
-                self.rom
-                    .write_u8(area_data_ptr_pc + room_index, new_area as isize)?;
+                self.journaled_write_u8(
+                    "write_map_areas",
+                    area_data_ptr_pc + room_index,
+                    new_area as isize,
+                )?;
             }
 
             // Advance the pointer keeping track of the next available free space:
@@ -369,10 +615,145 @@ impl<'a> Patcher<'a> {
     }
 }
 
+// An IPS hunk offset of exactly this value would be indistinguishable from the file's "EOF"
+// footer, so no hunk may start there.
+const IPS_EOF_MARKER: usize = 0x454F46;
+const IPS_MAX_HUNK_LEN: usize = 0xFFFF;
+
+fn write_ips_u24(out: &mut Vec<u8>, x: usize) {
+    out.push(((x >> 16) & 0xFF) as u8);
+    out.push(((x >> 8) & 0xFF) as u8);
+    out.push((x & 0xFF) as u8);
+}
+
+fn write_ips_u16(out: &mut Vec<u8>, x: u16) {
+    out.push(((x >> 8) & 0xFF) as u8);
+    out.push((x & 0xFF) as u8);
+}
+
+fn write_ips_literal_hunk(out: &mut Vec<u8>, offset: usize, data: &[u8]) {
+    write_ips_u24(out, offset);
+    write_ips_u16(out, data.len() as u16);
+    out.extend_from_slice(data);
+}
+
+fn write_ips_rle_hunk(out: &mut Vec<u8>, offset: usize, run_len: u16, value: u8) {
+    write_ips_u24(out, offset);
+    write_ips_u16(out, 0);
+    write_ips_u16(out, run_len);
+    out.push(value);
+}
+
+// Emits one differing run (`orig.data[offset..]` vs `payload`) as one or more IPS hunks, splitting
+// on the 0xFFFF max hunk length and emitting a cheaper RLE hunk whenever the remaining payload
+// starts with a run of 4+ identical bytes (a run that starts mid-chunk stays in the literal
+// hunk). Never lets a hunk boundary land exactly on the reserved `IPS_EOF_MARKER` offset: if a
+// natural split would start the next hunk there, the current hunk's length is nudged by one byte
+// (shrunk if it has slack to spare, otherwise grown by a trailing byte of real payload) so the
+// colliding offset ends up in the middle of a hunk instead of at the start of one. The only case
+// this function can't resolve on its own is a run that *begins* exactly on `IPS_EOF_MARKER`
+// (nothing earlier in `payload` to absorb it into); `make_patch` handles that by including one
+// extra (unchanged) leading byte in the run it passes in.
+fn write_ips_run(out: &mut Vec<u8>, mut offset: usize, mut payload: &[u8]) -> Result<()> {
+    while !payload.is_empty() {
+        ensure!(
+            offset <= 0xFFFFFF,
+            "IPS offset {offset:#x} exceeds the 24-bit range IPS patches can represent"
+        );
+
+        let rle_len = payload.iter().take_while(|&&b| b == payload[0]).count();
+        if rle_len >= 4 {
+            let mut run_len = rle_len.min(IPS_MAX_HUNK_LEN);
+            if offset + run_len == IPS_EOF_MARKER {
+                // `run_len >= 4`, so shrinking by one byte always leaves a well-formed (if
+                // slightly shorter) RLE hunk; the deferred byte rejoins `payload` for the next one.
+                run_len -= 1;
+            }
+            write_ips_rle_hunk(out, offset, run_len as u16, payload[0]);
+            offset += run_len;
+            payload = &payload[run_len..];
+        } else {
+            let mut chunk_len = payload.len().min(IPS_MAX_HUNK_LEN);
+            if offset + chunk_len == IPS_EOF_MARKER {
+                if chunk_len > 1 {
+                    chunk_len -= 1;
+                } else if chunk_len < payload.len() {
+                    chunk_len += 1;
+                }
+                // Otherwise this is the last byte of the run with nothing to spare either way;
+                // `make_patch` guarantees a run's start offset never collides, and a collision
+                // can only recur mid-run by reaching exactly `IPS_EOF_MARKER` again, which this
+                // adjustment already prevents on every other iteration.
+            }
+            write_ips_literal_hunk(out, offset, &payload[..chunk_len]);
+            offset += chunk_len;
+            payload = &payload[chunk_len..];
+        }
+    }
+    Ok(())
+}
+
+// Produces a standard IPS delta patch (`orig_rom.data` -> `rom.data`) so the randomized result can
+// be distributed without redistributing the copyrighted base ROM.
+pub fn make_patch(orig_rom: &Rom, rom: &Rom) -> Result<Vec<u8>> {
+    ensure!(
+        orig_rom.data.len() == rom.data.len(),
+        "make_patch requires the original and patched ROMs to be the same length"
+    );
+    let mut out: Vec<u8> = Vec::new();
+    out.extend_from_slice(b"PATCH");
+
+    let len = rom.data.len();
+    let mut i = 0;
+    while i < len {
+        if orig_rom.data[i] == rom.data[i] {
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        while end < len && orig_rom.data[end] != rom.data[end] {
+            end += 1;
+        }
+        // If this run would start exactly on the reserved EOF marker offset, pull in the
+        // preceding byte (unchanged between the two ROMs, since otherwise it would already be
+        // part of this run) so the hunk actually starts one byte earlier instead.
+        let start = if i == IPS_EOF_MARKER && i > 0 { i - 1 } else { i };
+        write_ips_run(&mut out, start, &rom.data[start..end])?;
+        i = end;
+    }
+
+    out.extend_from_slice(b"EOF");
+    Ok(out)
+}
+
+// Options controlling `make_rom_with_options` beyond the base rom/randomization/game_data inputs.
+// Bundled into one struct (rather than further positional arguments) since both fields are
+// opt-in knobs that most callers leave at their defaults.
+#[derive(Default)]
+pub struct PatchOptions {
+    // Treat any detected write collision (see `WriteJournal`) as a hard error instead of just
+    // logging it, for catching a patch-ordering or address-overlap bug in CI rather than at
+    // release time.
+    pub strict_write_collisions: bool,
+    // Hand-authored minimap tile templates, keyed by room name, for rooms with no sensible
+    // "copy from the original ROM" map graphics source. See `RoomMapPrefab`.
+    pub room_map_prefabs: HashMap<String, RoomMapPrefab>,
+}
+
 pub fn make_rom(
     base_rom_path: &Path,
     randomization: &Randomization,
     game_data: &GameData,
+) -> Result<Rom> {
+    make_rom_with_options(base_rom_path, randomization, game_data, &PatchOptions::default())
+}
+
+// Same as `make_rom`, but lets the caller customize behavior via `PatchOptions`.
+pub fn make_rom_with_options(
+    base_rom_path: &Path,
+    randomization: &Randomization,
+    game_data: &GameData,
+    options: &PatchOptions,
 ) -> Result<Rom> {
     let mut orig_rom = Rom::load(base_rom_path)?;
     let mut rom = orig_rom.clone();
@@ -382,12 +763,26 @@ pub fn make_rom(
         randomization,
         game_data,
         map: &randomization.map,
+        write_journal: WriteJournal::default(),
     };
-    patcher.apply_ips_patches()?;
+    let applied_patches = patcher.apply_ips_patches()?;
+    log::info!("Applied IPS patches: {:?}", applied_patches);
     patcher.place_items()?;
     patcher.connect_doors()?;
     patcher.fix_save_stations()?;
-    patcher.write_map_tilemaps()?;
+    patcher.write_map_tilemaps(&options.room_map_prefabs)?;
     patcher.write_map_areas()?;
+    if !patcher.write_journal.collisions.is_empty() {
+        log::warn!(
+            "Detected {} ROM write collision(s): {:?}",
+            patcher.write_journal.collisions.len(),
+            patcher.write_journal.collisions
+        );
+        ensure!(
+            !options.strict_write_collisions,
+            "{} ROM write collision(s) detected (strict mode)",
+            patcher.write_journal.collisions.len()
+        );
+    }
     Ok(rom)
 }